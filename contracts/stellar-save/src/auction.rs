@@ -0,0 +1,88 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+/// A member's bid for the current cycle's pot in a bidding group.
+///
+/// The bid is the amount the member is willing to take out of the pot.
+/// The lowest bid wins; the difference between the pot and the winning bid
+/// (the discount) is shared among the members who have not been paid yet.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bid {
+    /// Member who placed the bid.
+    pub bidder: Address,
+
+    /// Amount of the pot the bidder asks for, in stroops.
+    pub amount: i128,
+
+    /// Timestamp when the bid was placed or last updated.
+    pub placed_at: u64,
+}
+
+/// Returns the winning bid: the lowest amount, with the earliest bid
+/// winning ties. Returns `None` if there are no bids.
+pub fn winning_bid(bids: &Vec<Bid>) -> Option<Bid> {
+    let mut winner: Option<Bid> = None;
+    for bid in bids.iter() {
+        let better = match &winner {
+            None => true,
+            Some(current) => {
+                bid.amount < current.amount
+                    || (bid.amount == current.amount && bid.placed_at < current.placed_at)
+            }
+        };
+        if better {
+            winner = Some(bid);
+        }
+    }
+    winner
+}
+
+/// Splits a discount equally between `recipients` members.
+///
+/// # Returns
+/// `(share, remainder)` - each member's share, rounded down, and the
+/// undistributed remainder
+pub fn split_discount(discount: i128, recipients: u32) -> (i128, i128) {
+    if recipients == 0 || discount <= 0 {
+        return (0, discount.max(0));
+    }
+    let share = discount / recipients as i128;
+    (share, discount - share * recipients as i128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    fn bid(env: &Env, amount: i128, placed_at: u64) -> Bid {
+        Bid {
+            bidder: Address::generate(env),
+            amount,
+            placed_at,
+        }
+    }
+
+    #[test]
+    fn test_winning_bid_lowest_then_earliest() {
+        let env = Env::default();
+        let mut bids = Vec::new(&env);
+        assert!(winning_bid(&bids).is_none());
+
+        bids.push_back(bid(&env, 270, 1));
+        bids.push_back(bid(&env, 240, 5));
+        bids.push_back(bid(&env, 240, 3));
+
+        let winner = winning_bid(&bids).unwrap();
+        assert_eq!(winner.amount, 240);
+        assert_eq!(winner.placed_at, 3);
+    }
+
+    #[test]
+    fn test_split_discount() {
+        assert_eq!(split_discount(60, 2), (30, 0));
+        assert_eq!(split_discount(100, 3), (33, 1));
+        assert_eq!(split_discount(50, 0), (0, 50));
+        assert_eq!(split_discount(0, 4), (0, 0));
+    }
+}
@@ -0,0 +1,165 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::{
+    error::StellarSaveError,
+    group::Group,
+    storage::StorageKeyBuilder,
+    MemberProfile,
+};
+
+/// Per-invocation read cache for a single group.
+///
+/// Read-heavy endpoints touch the same group and member list many times.
+/// `GroupContext` loads the group once and lazily caches the member list, so
+/// each storage entry is read at most once per invocation.
+///
+/// The context is never persisted; it lives only for the duration of the
+/// contract call that created it.
+pub struct GroupContext<'a> {
+    env: &'a Env,
+
+    /// The group loaded from storage.
+    pub group: Group,
+
+    /// Cached member list, loaded on first use.
+    members: Option<Vec<Address>>,
+}
+
+impl<'a> GroupContext<'a> {
+    /// Loads a group into a new context.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `group_id` - ID of the group to load
+    ///
+    /// # Returns
+    /// * `Ok(GroupContext)` - Context holding the loaded group
+    /// * `Err(StellarSaveError::GroupNotFound)` - If the group doesn't exist
+    pub fn load(env: &'a Env, group_id: u64) -> Result<Self, StellarSaveError> {
+        let group_key = StorageKeyBuilder::group_data(group_id);
+        let group = env
+            .storage()
+            .persistent()
+            .get::<_, Group>(&group_key)
+            .ok_or(StellarSaveError::GroupNotFound)?;
+
+        Ok(Self {
+            env,
+            group,
+            members: None,
+        })
+    }
+
+    /// Returns the group's member list, reading it from storage only once.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Address>)` - Member addresses in join order
+    /// * `Err(StellarSaveError::GroupNotFound)` - If no member list is stored
+    pub fn members(&mut self) -> Result<Vec<Address>, StellarSaveError> {
+        if let Some(members) = &self.members {
+            return Ok(members.clone());
+        }
+
+        let members_key = StorageKeyBuilder::group_members(self.group.id);
+        let members: Vec<Address> = self
+            .env
+            .storage()
+            .persistent()
+            .get(&members_key)
+            .ok_or(StellarSaveError::GroupNotFound)?;
+
+        self.members = Some(members.clone());
+        Ok(members)
+    }
+
+    /// Checks whether a member was the payout recipient of any cycle so far.
+    ///
+    /// Reads the single received flag written when the payout was executed.
+    pub fn has_received_payout(&self, member: &Address) -> bool {
+        let key = StorageKeyBuilder::member_payout_received(self.group.id, member.clone());
+        self.env.storage().persistent().get(&key).unwrap_or(false)
+    }
+
+    /// Reads a member's payout position from their member profile.
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - The member's 0-indexed payout position
+    /// * `Err(StellarSaveError::NotMember)` - If the member has no profile
+    pub fn payout_position(&self, member: &Address) -> Result<u32, StellarSaveError> {
+        let key = StorageKeyBuilder::member_profile(self.group.id, member.clone());
+        let member_profile = self
+            .env
+            .storage()
+            .persistent()
+            .get::<_, MemberProfile>(&key)
+            .ok_or(StellarSaveError::NotMember)?;
+
+        Ok(member_profile.payout_position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StellarSaveContract;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    fn store_group(env: &Env, group_id: u64, current_cycle: u32) {
+        let creator = Address::generate(env);
+        let mut group = Group::new(group_id, creator, 100, 3600, 5, 2, 0);
+        group.current_cycle = current_cycle;
+        env.storage()
+            .persistent()
+            .set(&StorageKeyBuilder::group_data(group_id), &group);
+    }
+
+    #[test]
+    fn test_load_missing_group() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, StellarSaveContract);
+
+        env.as_contract(&contract_id, || {
+            let result = GroupContext::load(&env, 42);
+            assert_eq!(result.err(), Some(StellarSaveError::GroupNotFound));
+        });
+    }
+
+    #[test]
+    fn test_members_cached() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, StellarSaveContract);
+
+        env.as_contract(&contract_id, || {
+            store_group(&env, 1, 0);
+            let mut members = Vec::new(&env);
+            members.push_back(Address::generate(&env));
+            let members_key = StorageKeyBuilder::group_members(1);
+            env.storage().persistent().set(&members_key, &members);
+
+            let mut ctx = GroupContext::load(&env, 1).unwrap();
+            assert_eq!(ctx.members().unwrap().len(), 1);
+
+            // Later writes are not observed by the cached context
+            env.storage().persistent().remove(&members_key);
+            assert_eq!(ctx.members().unwrap().len(), 1);
+        });
+    }
+
+    #[test]
+    fn test_has_received_payout() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, StellarSaveContract);
+
+        env.as_contract(&contract_id, || {
+            store_group(&env, 1, 2);
+            let paid = Address::generate(&env);
+            let unpaid = Address::generate(&env);
+            env.storage()
+                .persistent()
+                .set(&StorageKeyBuilder::member_payout_received(1, paid.clone()), &true);
+
+            let ctx = GroupContext::load(&env, 1).unwrap();
+            assert!(ctx.has_received_payout(&paid));
+            assert!(!ctx.has_received_payout(&unpaid));
+        });
+    }
+}
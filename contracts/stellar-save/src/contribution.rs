@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, Symbol};
 
 /// Contribution Record structure for tracking individual member contributions.
 /// 
@@ -28,6 +28,23 @@ pub struct ContributionRecord {
     /// Timestamp when the contribution was made (Unix timestamp in seconds).
     /// Used for tracking contribution timing and enforcing deadlines.
     pub timestamp: u64,
+
+    /// Whether this contribution was collected offline and attested on-chain.
+    /// Offline contributions count toward cycle completeness but carry no
+    /// tokens, so they are excluded from pool and balance accounting.
+    pub offline: bool,
+
+    /// Optional reference the contributor attached, e.g. an invoice number,
+    /// for reconciling against off-chain bookkeeping.
+    pub memo: Option<Symbol>,
+
+    /// Position of this contribution among all contributions to the group,
+    /// starting at 1. Assigned by the contract when the record is stored.
+    pub sequence: u32,
+
+    /// USD target of the cycle, with `USD_DECIMALS` decimals, when the group
+    /// is pegged to USD; `amount` is what the target cost in tokens.
+    pub usd_target: Option<i128>,
 }
 
 impl ContributionRecord {
@@ -59,6 +76,45 @@ impl ContributionRecord {
             cycle_number,
             amount,
             timestamp,
+            offline: false,
+            memo: None,
+            sequence: 0,
+            usd_target: None,
+        }
+    }
+
+    /// Attaches a memo to the record.
+    pub fn with_memo(mut self, memo: Symbol) -> Self {
+        self.memo = Some(memo);
+        self
+    }
+
+    /// Creates a ContributionRecord marking a contribution collected offline.
+    /// 
+    /// The `amount` is the nominal cycle amount paid in cash; no tokens were
+    /// transferred to the contract for this record.
+    /// 
+    /// # Panics
+    /// Panics if amount is not greater than 0.
+    pub fn new_offline(
+        member_address: Address,
+        group_id: u64,
+        cycle_number: u32,
+        amount: i128,
+        timestamp: u64,
+    ) -> Self {
+        let mut record = Self::new(member_address, group_id, cycle_number, amount, timestamp);
+        record.offline = true;
+        record
+    }
+
+    /// Returns the amount this record contributed in tokens.
+    /// Offline records contribute nothing on-chain.
+    pub fn token_amount(&self) -> i128 {
+        if self.offline {
+            0
+        } else {
+            self.amount
         }
     }
 
@@ -86,6 +142,45 @@ impl ContributionRecord {
     }
 }
 
+/// A cycle obligation a member deferred to the end of the rotation.
+/// 
+/// A member facing hardship may, once per rotation, skip one cycle's
+/// contribution. The recipient of that cycle is paid short, and the member
+/// settles the deferred amount to that recipient in an extra cycle after the
+/// regular rotation ends.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeferredObligation {
+    /// Member who deferred the obligation.
+    pub member_address: Address,
+
+    /// ID of the group the obligation belongs to.
+    pub group_id: u64,
+
+    /// Cycle whose contribution was deferred.
+    pub cycle_number: u32,
+
+    /// Amount owed, equal to the group's contribution amount.
+    pub amount: i128,
+
+    /// Address that approved the deferral (the group creator).
+    pub approved_by: Address,
+
+    /// Deadline for settlement: the end of the extra cycle appended to the
+    /// rotation for this member.
+    pub due_at: u64,
+
+    /// Whether the obligation has been settled.
+    pub settled: bool,
+}
+
+impl DeferredObligation {
+    /// Returns true if the obligation is unsettled past its due date.
+    pub fn is_overdue(&self, now: u64) -> bool {
+        !self.settled && now > self.due_at
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,6 +204,40 @@ mod tests {
         assert_eq!(contribution.cycle_number, 0);
         assert_eq!(contribution.amount, 10_000_000);
         assert_eq!(contribution.timestamp, 1234567890);
+        assert!(!contribution.offline);
+        assert_eq!(contribution.memo, None);
+        assert_eq!(contribution.sequence, 0);
+    }
+
+    #[test]
+    fn test_contribution_record_with_memo() {
+        let env = Env::default();
+        let member = Address::generate(&env);
+        let memo = Symbol::new(&env, "INV_2041");
+
+        let contribution = ContributionRecord::new(member, 1, 0, 10_000_000, 1234567890)
+            .with_memo(memo.clone());
+
+        assert_eq!(contribution.memo, Some(memo));
+    }
+
+    #[test]
+    fn test_offline_contribution_record() {
+        let env = Env::default();
+        let member = Address::generate(&env);
+        
+        let contribution = ContributionRecord::new_offline(
+            member.clone(),
+            1,
+            0,
+            10_000_000,
+            1234567890,
+        );
+
+        assert!(contribution.offline);
+        assert_eq!(contribution.amount, 10_000_000);
+        assert_eq!(contribution.token_amount(), 0);
+        assert!(contribution.validate());
     }
 
     #[test]
@@ -201,6 +330,29 @@ mod tests {
         assert_ne!(contribution1.timestamp, contribution2.timestamp);
     }
 
+    #[test]
+    fn test_deferred_obligation_overdue() {
+        let env = Env::default();
+        let member = Address::generate(&env);
+        let creator = Address::generate(&env);
+
+        let mut obligation = DeferredObligation {
+            member_address: member,
+            group_id: 1,
+            cycle_number: 2,
+            amount: 10_000_000,
+            approved_by: creator,
+            due_at: 1_000,
+            settled: false,
+        };
+
+        assert!(!obligation.is_overdue(1_000));
+        assert!(obligation.is_overdue(1_001));
+
+        obligation.settled = true;
+        assert!(!obligation.is_overdue(1_001));
+    }
+
     #[test]
     fn test_contribution_across_cycles() {
         let env = Env::default();
@@ -0,0 +1,105 @@
+use soroban_sdk::contracttype;
+use crate::{error::StellarSaveError, group::Group};
+
+/// Where a cycle stands against ledger time.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CyclePhase {
+    /// Before the deadline; contributions are on time.
+    Open,
+
+    /// Past the deadline but within the grace period; contributions are late.
+    Grace,
+
+    /// Past the grace period; the cycle can no longer take contributions and
+    /// may be advanced with `try_advance_cycle`.
+    Ended,
+}
+
+/// Ledger-time boundaries of a single cycle.
+///
+/// Cycles end on time alone: once the deadline and grace period have passed
+/// the cycle is over whether or not every member contributed, so a keeper can
+/// resolve the missing contributions and roll the group on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CycleClock {
+    /// Timestamp after which contributions count as late.
+    pub deadline: u64,
+
+    /// Timestamp after which the cycle has ended.
+    pub ends_at: u64,
+}
+
+impl CycleClock {
+    /// Returns the clock of a started group's cycle at `now`, accounting for
+    /// any time the group spent paused.
+    ///
+    /// # Returns
+    /// * `Err(StellarSaveError::InvalidState)` - Group hasn't been started
+    /// * `Err(StellarSaveError::Overflow)` - Deadline overflows
+    pub fn for_cycle(group: &Group, cycle: u32, now: u64) -> Result<Self, StellarSaveError> {
+        if !group.started {
+            return Err(StellarSaveError::InvalidState);
+        }
+        let deadline = group
+            .cycle_deadline(cycle, now)
+            .ok_or(StellarSaveError::Overflow)?;
+        Ok(Self::new(deadline, group.grace_period))
+    }
+
+    /// Builds a clock from a deadline and grace period in seconds.
+    pub fn new(deadline: u64, grace_period: u64) -> Self {
+        Self {
+            deadline,
+            ends_at: deadline.saturating_add(grace_period),
+        }
+    }
+
+    /// Returns the cycle's phase at `now`.
+    pub fn phase(&self, now: u64) -> CyclePhase {
+        if now <= self.deadline {
+            CyclePhase::Open
+        } else if now <= self.ends_at {
+            CyclePhase::Grace
+        } else {
+            CyclePhase::Ended
+        }
+    }
+
+    /// Returns true once the deadline and grace period have both passed.
+    pub fn has_ended(&self, now: u64) -> bool {
+        self.phase(now) == CyclePhase::Ended
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phase() {
+        let clock = CycleClock::new(1_000, 100);
+
+        assert_eq!(clock.phase(1_000), CyclePhase::Open);
+        assert_eq!(clock.phase(1_001), CyclePhase::Grace);
+        assert_eq!(clock.phase(1_100), CyclePhase::Grace);
+        assert_eq!(clock.phase(1_101), CyclePhase::Ended);
+        assert!(clock.has_ended(1_101));
+    }
+
+    #[test]
+    fn test_no_grace_period() {
+        let clock = CycleClock::new(1_000, 0);
+
+        assert_eq!(clock.phase(1_000), CyclePhase::Open);
+        assert_eq!(clock.phase(1_001), CyclePhase::Ended);
+    }
+
+    #[test]
+    fn test_grace_period_saturates() {
+        let clock = CycleClock::new(u64::MAX - 1, 10);
+
+        assert_eq!(clock.ends_at, u64::MAX);
+        assert!(!clock.has_ended(u64::MAX));
+    }
+}
@@ -0,0 +1,20 @@
+use soroban_sdk::{contractclient, Address, Env, Vec};
+
+/// Cross-contract interface of a DEX router such as Soroswap's.
+///
+/// The router pulls the input token from `to`, routes it through `path`
+/// and sends exactly `amount_out` of the last token in the path to `to`.
+#[contractclient(name = "DexRouterClient")]
+pub trait DexRouter {
+    /// Swaps at most `amount_in_max` of `path[0]` for exactly `amount_out`
+    /// of the last token in `path`. Returns the amount at every hop,
+    /// starting with the amount taken in.
+    fn swap_tokens_for_exact_tokens(
+        env: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Vec<i128>;
+}
@@ -0,0 +1,99 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+/// How an arbiter settles a dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeResolution {
+    /// Reject the dispute without any further action.
+    Dismiss,
+
+    /// Refund an amount to an address from the group's insurance pool:
+    /// `(recipient, amount)`.
+    Refund(Address, i128),
+
+    /// Move a member's security deposit into the group's insurance pool.
+    SlashDeposit(Address),
+
+    /// Re-execute the disputed cycle's payout in favour of another member:
+    /// whatever of it is still unclaimed is credited to them instead.
+    ReassignPayout(Address),
+}
+
+/// Where a dispute stands.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeStatus {
+    /// Waiting for an arbiter.
+    Open,
+
+    /// Settled by an arbiter with the given decision.
+    Resolved(DisputeResolution),
+}
+
+/// A dispute a member raised about one cycle of a group.
+///
+/// The evidence itself stays off-chain; only its hash is anchored here so
+/// the arbiter and members can verify what was submitted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    /// Dispute ID, sequential within the group starting at 1.
+    pub id: u32,
+
+    /// Group the dispute belongs to.
+    pub group_id: u64,
+
+    /// Cycle the dispute is about.
+    pub cycle: u32,
+
+    /// Member who opened the dispute.
+    pub opened_by: Address,
+
+    /// Hash of the off-chain evidence (e.g. an IPFS digest).
+    pub evidence_hash: BytesN<32>,
+
+    /// Timestamp when the dispute was opened.
+    pub opened_at: u64,
+
+    /// Open, or the arbiter's decision once resolved.
+    pub status: DisputeStatus,
+
+    /// Arbiter who resolved the dispute.
+    pub resolved_by: Option<Address>,
+
+    /// Timestamp when the dispute was resolved, 0 while open.
+    pub resolved_at: u64,
+}
+
+impl Dispute {
+    /// Returns true until an arbiter has resolved the dispute.
+    pub fn is_open(&self) -> bool {
+        self.status == DisputeStatus::Open
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_is_open_until_resolved() {
+        let env = Env::default();
+        let mut dispute = Dispute {
+            id: 1,
+            group_id: 1,
+            cycle: 0,
+            opened_by: Address::generate(&env),
+            evidence_hash: BytesN::from_array(&env, &[7; 32]),
+            opened_at: 100,
+            status: DisputeStatus::Open,
+            resolved_by: None,
+            resolved_at: 0,
+        };
+        assert!(dispute.is_open());
+
+        dispute.status = DisputeStatus::Resolved(DisputeResolution::Dismiss);
+        assert!(!dispute.is_open());
+    }
+}
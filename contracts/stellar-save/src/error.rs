@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype};
+use soroban_sdk::{contracterror, contracttype, Address};
 
 /// Comprehensive error types for Stellar-Save contract operations.
 /// 
@@ -22,6 +22,34 @@ pub enum StellarSaveError {
     /// Error Code: 1003
     InvalidState = 1003,
     
+    /// The group already carries the maximum number of custom rules.
+    /// Error Code: 1004
+    TooManyRules = 1004,
+    
+    /// The token is not on the admin-managed allowlist.
+    /// Error Code: 1005
+    TokenNotAllowed = 1005,
+    
+    /// The group has not reached its minimum member count.
+    /// Error Code: 1006
+    InsufficientMembers = 1006,
+    
+    /// The slug is already registered to another group.
+    /// Error Code: 1007
+    SlugTaken = 1007,
+
+    /// The specified proposal does not exist in the group.
+    /// Error Code: 1008
+    ProposalNotFound = 1008,
+
+    /// The specified group template does not exist.
+    /// Error Code: 1009
+    TemplateNotFound = 1009,
+
+    /// The specified dispute does not exist in the group.
+    /// Error Code: 1010
+    DisputeNotFound = 1010,
+    
     // Member-related errors (2000-2999)
     /// The address is already a member of this group.
     /// Error Code: 2001
@@ -35,6 +63,30 @@ pub enum StellarSaveError {
     /// Error Code: 2003
     Unauthorized = 2003,
     
+    /// The member has already used their hardship deferral this rotation.
+    /// Error Code: 2004
+    DeferralAlreadyUsed = 2004,
+    
+    /// The address's reputation score is below the group's minimum.
+    /// Error Code: 2005
+    ReputationTooLow = 2005,
+
+    /// The member has already voted on this proposal.
+    /// Error Code: 2006
+    AlreadyVoted = 2006,
+
+    /// A member tried to refer themselves.
+    /// Error Code: 2007
+    SelfReferral = 2007,
+
+    /// The address is on the protocol-wide ban list.
+    /// Error Code: 2008
+    AddressBanned = 2008,
+
+    /// The address already belongs to as many groups as it may.
+    /// Error Code: 2009
+    TooManyGroups = 2009,
+    
     // Contribution-related errors (3000-3999)
     /// The contribution amount is invalid (zero, negative, or incorrect).
     /// Error Code: 3001
@@ -52,6 +104,15 @@ pub enum StellarSaveError {
     /// Error Code: 3004
     ContributionNotFound = 3004,
     
+    /// The price oracle has no recent price for the group's token.
+    /// Error Code: 3005
+    PriceUnavailable = 3005,
+
+    /// The current cycle's contribution deadline, including any grace
+    /// period, has passed.
+    /// Error Code: 3006
+    DeadlinePassed = 3006,
+    
     // Payout-related errors (4000-4999)
     /// The payout operation failed due to insufficient funds or transfer error.
     /// Error Code: 4001
@@ -65,6 +126,10 @@ pub enum StellarSaveError {
     /// Error Code: 4003
     InvalidRecipient = 4003,
     
+    /// There is no unclaimed payout for this cycle.
+    /// Error Code: 4004
+    NothingToClaim = 4004,
+    
     // System-related errors (9000-9999)
     /// An internal contract error occurred.
     /// Error Code: 9001
@@ -77,6 +142,10 @@ pub enum StellarSaveError {
     /// Added for ID Generation: The counter has reached its maximum limit.
     /// Error Code: 9003
     Overflow = 9003,
+
+    /// The caller already submitted an operation with this ID.
+    /// Error Code: 9004
+    DuplicateOperation = 9004,
 }
 
 impl StellarSaveError {
@@ -96,6 +165,27 @@ impl StellarSaveError {
             StellarSaveError::InvalidState => {
                 "The group is not in a valid state for this operation. Check group status."
             }
+            StellarSaveError::TooManyRules => {
+                "Too many custom rules for this group. Remove a rule before adding another."
+            }
+            StellarSaveError::TokenNotAllowed => {
+                "This token is not on the allowlist. Choose an approved asset for the group."
+            }
+            StellarSaveError::InsufficientMembers => {
+                "The group does not have enough members yet. Wait for more members to join."
+            }
+            StellarSaveError::SlugTaken => {
+                "This slug is already in use by another group. Choose a different one."
+            }
+            StellarSaveError::ProposalNotFound => {
+                "The specified proposal does not exist. Please verify the proposal ID."
+            }
+            StellarSaveError::TemplateNotFound => {
+                "The specified group template does not exist. Please verify the template ID."
+            }
+            StellarSaveError::DisputeNotFound => {
+                "The specified dispute does not exist. Please verify the dispute ID."
+            }
             
             // Member-related errors
             StellarSaveError::AlreadyMember => {
@@ -107,6 +197,24 @@ impl StellarSaveError {
             StellarSaveError::Unauthorized => {
                 "You are not authorized to perform this operation. Check permissions."
             }
+            StellarSaveError::DeferralAlreadyUsed => {
+                "You have already deferred a contribution in this rotation."
+            }
+            StellarSaveError::ReputationTooLow => {
+                "Your reputation score is below this group's minimum requirement."
+            }
+            StellarSaveError::AlreadyVoted => {
+                "You have already voted on this proposal."
+            }
+            StellarSaveError::SelfReferral => {
+                "You cannot refer yourself to a group."
+            }
+            StellarSaveError::AddressBanned => {
+                "This address is banned from creating or joining groups."
+            }
+            StellarSaveError::TooManyGroups => {
+                "You already belong to the maximum number of groups."
+            }
             
             // Contribution-related errors
             StellarSaveError::InvalidAmount => {
@@ -121,6 +229,12 @@ impl StellarSaveError {
             StellarSaveError::ContributionNotFound => {
                 "The contribution record was not found for the specified member and cycle."
             }
+            StellarSaveError::PriceUnavailable => {
+                "No recent oracle price is available to compute the contribution amount. Try again later."
+            }
+            StellarSaveError::DeadlinePassed => {
+                "The contribution deadline for this cycle has passed."
+            }
             
             // Payout-related errors
             StellarSaveError::PayoutFailed => {
@@ -132,6 +246,9 @@ impl StellarSaveError {
             StellarSaveError::InvalidRecipient => {
                 "The specified recipient is not eligible for payout in this cycle."
             }
+            StellarSaveError::NothingToClaim => {
+                "There is no unclaimed payout for this cycle."
+            }
             
             // System-related errors
             StellarSaveError::InternalError => {
@@ -143,6 +260,9 @@ impl StellarSaveError {
             StellarSaveError::Overflow => {
                 "The ID counter has reached its maximum limit. No more IDs can be generated."
             }
+            StellarSaveError::DuplicateOperation => {
+                "This operation was already submitted. The earlier submission has been applied."
+            }
         }
     }
     
@@ -165,6 +285,52 @@ impl StellarSaveError {
             _ => ErrorCategory::Unknown,
         }
     }
+
+    /// Returns true if the same call may succeed later without the caller
+    /// changing anything: the error reports a condition that clears on its
+    /// own, such as contributions still arriving or a stale oracle price.
+    /// 
+    /// Every other error is terminal for the request as made; retrying it
+    /// unchanged will fail the same way. Client SDKs can back off and retry
+    /// retryable errors and surface terminal ones to the user.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            StellarSaveError::InsufficientMembers
+            | StellarSaveError::CycleNotComplete
+            | StellarSaveError::PriceUnavailable
+            | StellarSaveError::PayoutFailed
+            | StellarSaveError::InternalError => true,
+
+            StellarSaveError::GroupNotFound
+            | StellarSaveError::GroupFull
+            | StellarSaveError::InvalidState
+            | StellarSaveError::TooManyRules
+            | StellarSaveError::TokenNotAllowed
+            | StellarSaveError::SlugTaken
+            | StellarSaveError::ProposalNotFound
+            | StellarSaveError::TemplateNotFound
+            | StellarSaveError::DisputeNotFound
+            | StellarSaveError::AlreadyMember
+            | StellarSaveError::NotMember
+            | StellarSaveError::Unauthorized
+            | StellarSaveError::DeferralAlreadyUsed
+            | StellarSaveError::ReputationTooLow
+            | StellarSaveError::AlreadyVoted
+            | StellarSaveError::SelfReferral
+            | StellarSaveError::AddressBanned
+            | StellarSaveError::TooManyGroups
+            | StellarSaveError::InvalidAmount
+            | StellarSaveError::AlreadyContributed
+            | StellarSaveError::ContributionNotFound
+            | StellarSaveError::DeadlinePassed
+            | StellarSaveError::PayoutAlreadyProcessed
+            | StellarSaveError::InvalidRecipient
+            | StellarSaveError::NothingToClaim
+            | StellarSaveError::DataCorruption
+            | StellarSaveError::Overflow
+            | StellarSaveError::DuplicateOperation => false,
+        }
+    }
 }
 
 /// Error categories for grouping related error types.
@@ -190,6 +356,67 @@ pub enum ErrorCategory {
     Unknown,
 }
 
+/// Context of an error: the group, cycle, address and values involved.
+/// 
+/// Error codes carry no data, so the contract publishes this as an
+/// `error_context` event just before returning the error. The event is
+/// rolled back with the failed invocation, but transaction simulation and
+/// the diagnostic events of a failed transaction still include it.
+/// 
+/// `expected` and `actual` hold the value the operation required and the
+/// value it found: amounts for pool and contribution errors, ledger
+/// timestamps for deadline errors.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErrorContext {
+    /// Group the failed operation was on.
+    pub group_id: u64,
+
+    /// Cycle the error is about, if any.
+    pub cycle: Option<u32>,
+
+    /// Address the error is about, if any.
+    pub address: Option<Address>,
+
+    /// Value the operation required, if any.
+    pub expected: Option<i128>,
+
+    /// Value the operation found, if any.
+    pub actual: Option<i128>,
+}
+
+impl ErrorContext {
+    /// Creates a context naming only the group.
+    pub fn new(group_id: u64) -> Self {
+        Self {
+            group_id,
+            cycle: None,
+            address: None,
+            expected: None,
+            actual: None,
+        }
+    }
+
+    /// Attaches the cycle the error is about.
+    pub fn with_cycle(mut self, cycle: u32) -> Self {
+        self.cycle = Some(cycle);
+        self
+    }
+
+    /// Attaches the address the error is about.
+    pub fn with_address(mut self, address: &Address) -> Self {
+        self.address = Some(address.clone());
+        self
+    }
+
+    /// Attaches the value required and the value found.
+    pub fn with_values(mut self, expected: i128, actual: i128) -> Self {
+        self.expected = Some(expected);
+        self.actual = Some(actual);
+        self
+    }
+}
+
 /// Result type alias for contract operations.
 /// 
 /// This provides a convenient way to return either a success value
@@ -199,6 +426,25 @@ pub type ContractResult<T> = Result<T, StellarSaveError>;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_error_context_builder() {
+        let env = Env::default();
+        let member = Address::generate(&env);
+
+        let context = ErrorContext::new(7)
+            .with_cycle(2)
+            .with_address(&member)
+            .with_values(100, 40);
+
+        assert_eq!(context.group_id, 7);
+        assert_eq!(context.cycle, Some(2));
+        assert_eq!(context.address, Some(member));
+        assert_eq!(context.expected, Some(100));
+        assert_eq!(context.actual, Some(40));
+        assert_eq!(ErrorContext::new(7).cycle, None);
+    }
 
     #[test]
     fn test_error_codes() {
@@ -206,21 +452,38 @@ mod tests {
         assert_eq!(StellarSaveError::GroupNotFound.code(), 1001);
         assert_eq!(StellarSaveError::GroupFull.code(), 1002);
         assert_eq!(StellarSaveError::InvalidState.code(), 1003);
+        assert_eq!(StellarSaveError::TooManyRules.code(), 1004);
+        assert_eq!(StellarSaveError::TokenNotAllowed.code(), 1005);
+        assert_eq!(StellarSaveError::InsufficientMembers.code(), 1006);
+        assert_eq!(StellarSaveError::SlugTaken.code(), 1007);
+        assert_eq!(StellarSaveError::ProposalNotFound.code(), 1008);
+        assert_eq!(StellarSaveError::TemplateNotFound.code(), 1009);
+        assert_eq!(StellarSaveError::DisputeNotFound.code(), 1010);
         
         assert_eq!(StellarSaveError::AlreadyMember.code(), 2001);
         assert_eq!(StellarSaveError::NotMember.code(), 2002);
         assert_eq!(StellarSaveError::Unauthorized.code(), 2003);
+        assert_eq!(StellarSaveError::DeferralAlreadyUsed.code(), 2004);
+        assert_eq!(StellarSaveError::ReputationTooLow.code(), 2005);
+        assert_eq!(StellarSaveError::AlreadyVoted.code(), 2006);
+        assert_eq!(StellarSaveError::SelfReferral.code(), 2007);
+        assert_eq!(StellarSaveError::AddressBanned.code(), 2008);
+        assert_eq!(StellarSaveError::TooManyGroups.code(), 2009);
         
         assert_eq!(StellarSaveError::InvalidAmount.code(), 3001);
         assert_eq!(StellarSaveError::AlreadyContributed.code(), 3002);
         assert_eq!(StellarSaveError::CycleNotComplete.code(), 3003);
+        assert_eq!(StellarSaveError::PriceUnavailable.code(), 3005);
+        assert_eq!(StellarSaveError::DeadlinePassed.code(), 3006);
         
         assert_eq!(StellarSaveError::PayoutFailed.code(), 4001);
         assert_eq!(StellarSaveError::PayoutAlreadyProcessed.code(), 4002);
         assert_eq!(StellarSaveError::InvalidRecipient.code(), 4003);
+        assert_eq!(StellarSaveError::NothingToClaim.code(), 4004);
         
         assert_eq!(StellarSaveError::InternalError.code(), 9001);
         assert_eq!(StellarSaveError::DataCorruption.code(), 9002);
+        assert_eq!(StellarSaveError::DuplicateOperation.code(), 9004);
     }
 
     #[test]
@@ -242,6 +505,25 @@ mod tests {
         assert_eq!(StellarSaveError::DataCorruption.category(), ErrorCategory::System);
     }
 
+    #[test]
+    fn test_error_retryability() {
+        // Conditions that clear on their own
+        assert!(StellarSaveError::CycleNotComplete.is_retryable());
+        assert!(StellarSaveError::InsufficientMembers.is_retryable());
+        assert!(StellarSaveError::PriceUnavailable.is_retryable());
+        assert!(StellarSaveError::PayoutFailed.is_retryable());
+        assert!(StellarSaveError::InternalError.is_retryable());
+
+        // Terminal for the request as made
+        assert!(!StellarSaveError::GroupNotFound.is_retryable());
+        assert!(!StellarSaveError::Unauthorized.is_retryable());
+        assert!(!StellarSaveError::AlreadyContributed.is_retryable());
+        assert!(!StellarSaveError::DeadlinePassed.is_retryable());
+        assert!(!StellarSaveError::PayoutAlreadyProcessed.is_retryable());
+        assert!(!StellarSaveError::DataCorruption.is_retryable());
+        assert!(!StellarSaveError::DuplicateOperation.is_retryable());
+    }
+
     #[test]
     fn test_error_messages() {
         // Test that all errors have non-empty messages
@@ -249,17 +531,24 @@ mod tests {
             StellarSaveError::GroupNotFound,
             StellarSaveError::GroupFull,
             StellarSaveError::InvalidState,
+            StellarSaveError::DisputeNotFound,
             StellarSaveError::AlreadyMember,
             StellarSaveError::NotMember,
             StellarSaveError::Unauthorized,
+            StellarSaveError::SelfReferral,
+            StellarSaveError::AddressBanned,
+            StellarSaveError::TooManyGroups,
             StellarSaveError::InvalidAmount,
             StellarSaveError::AlreadyContributed,
             StellarSaveError::CycleNotComplete,
+            StellarSaveError::PriceUnavailable,
+            StellarSaveError::DeadlinePassed,
             StellarSaveError::PayoutFailed,
             StellarSaveError::PayoutAlreadyProcessed,
             StellarSaveError::InvalidRecipient,
             StellarSaveError::InternalError,
             StellarSaveError::DataCorruption,
+            StellarSaveError::DuplicateOperation,
         ];
 
         for error in &errors {
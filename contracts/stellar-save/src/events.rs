@@ -1,4 +1,6 @@
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+use crate::ContractConfig;
+use crate::error::{ErrorContext, StellarSaveError};
 
 /// Event emitted when a new savings group is created.
 #[contracttype]
@@ -32,6 +34,19 @@ pub struct MemberLeft {
     pub left_at: u64,
 }
 
+/// Event emitted when the creator removes a member before activation.
+/// Carries the group's custom rules so the context of the removal is on record.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberRemoved {
+    pub group_id: u64,
+    pub member: Address,
+    pub removed_by: Address,
+    pub member_count: u32,
+    pub rules: Vec<Symbol>,
+    pub removed_at: u64,
+}
+
 /// Event emitted when a member makes a contribution.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -44,6 +59,45 @@ pub struct ContributionMade {
     pub contributed_at: u64,
 }
 
+/// Event emitted when a member contributes during the grace period after the deadline.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LateContribution {
+    pub group_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+    pub late_fee: i128,
+    pub cycle: u32,
+    pub contributed_at: u64,
+}
+
+/// Event emitted when a member is flagged as defaulted on a cycle.
+/// `covered` is the part of the contribution taken from the member's deposit;
+/// `shortfall` is the uncovered rest, by which the cycle payout is reduced.
+/// Carries the group's custom rules so the context of the default is on record.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberDefaulted {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub covered: i128,
+    pub shortfall: i128,
+    pub rules: Vec<Symbol>,
+    pub defaulted_at: u64,
+}
+
+/// Event emitted when an attestor records a contribution collected offline.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfflineContributionAttested {
+    pub group_id: u64,
+    pub member: Address,
+    pub attestor: Address,
+    pub cycle: u32,
+    pub attested_at: u64,
+}
+
 /// Event emitted when a payout is executed.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -77,7 +131,72 @@ pub struct GroupStatusChanged {
     pub changed_at: u64,
 }
 
+/// Event emitted when a group is activated and its first cycle starts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupActivated {
+    pub group_id: u64,
+    pub member_count: u32,
+    pub activated_at: u64,
+}
+
+/// Event emitted when a member's payout is withdrawn.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutClaimed {
+    pub group_id: u64,
+    pub recipient: Address,
+    pub cycle: u32,
+    pub amount: i128,
+    pub claimed_at: u64,
+}
+
+/// Event emitted when a member locks their security deposit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositLocked {
+    pub group_id: u64,
+    pub member: Address,
+    pub amount: i128,
+    pub locked_at: u64,
+}
+
+/// Event emitted when a missed contribution is taken from a member's deposit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositSlashed {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub amount: i128,
+    pub slashed_at: u64,
+}
+
+/// Event emitted when what remains of a deposit is returned.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositRefunded {
+    pub group_id: u64,
+    pub member: Address,
+    pub amount: i128,
+    pub refunded_at: u64,
+}
+
+/// Event emitted when the admin updates the global contract configuration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigUpdated {
+    pub admin: Address,
+    pub config: ContractConfig,
+    pub updated_at: u64,
+}
+
 /// Utility functions for emitting events.
+/// 
+/// Every group event is published with the topics `(action, group_id)`,
+/// where `action` is the snake_case event name, and the event struct as
+/// data, so indexers can filter by action and by group alike. Contract-wide
+/// events use the acting address in place of the group ID.
 pub struct EventEmitter;
 
 impl EventEmitter {
@@ -98,7 +217,7 @@ impl EventEmitter {
             max_members,
             created_at,
         };
-        env.events().publish(("group_created",), event);
+        env.events().publish((Symbol::new(env, "group_created"), group_id), event);
     }
     
     pub fn emit_member_joined(
@@ -114,7 +233,7 @@ impl EventEmitter {
             member_count,
             joined_at,
         };
-        env.events().publish(("member_joined",), event);
+        env.events().publish((Symbol::new(env, "member_joined"), group_id), event);
     }
     
     pub fn emit_member_left(
@@ -130,7 +249,27 @@ impl EventEmitter {
             member_count,
             left_at,
         };
-        env.events().publish(("member_left",), event);
+        env.events().publish((Symbol::new(env, "member_left"), group_id), event);
+    }
+    
+    pub fn emit_member_removed(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        removed_by: Address,
+        member_count: u32,
+        rules: Vec<Symbol>,
+        removed_at: u64,
+    ) {
+        let event = MemberRemoved {
+            group_id,
+            member,
+            removed_by,
+            member_count,
+            rules,
+            removed_at,
+        };
+        env.events().publish((Symbol::new(env, "member_removed"), group_id), event);
     }
     
     pub fn emit_contribution_made(
@@ -150,7 +289,67 @@ impl EventEmitter {
             cycle_total,
             contributed_at,
         };
-        env.events().publish(("contribution_made",), event);
+        env.events().publish((Symbol::new(env, "contribution_made"), group_id), event);
+    }
+    
+    pub fn emit_late_contribution(
+        env: &Env,
+        group_id: u64,
+        contributor: Address,
+        amount: i128,
+        late_fee: i128,
+        cycle: u32,
+        contributed_at: u64,
+    ) {
+        let event = LateContribution {
+            group_id,
+            contributor,
+            amount,
+            late_fee,
+            cycle,
+            contributed_at,
+        };
+        env.events().publish((Symbol::new(env, "late_contribution"), group_id), event);
+    }
+    
+    pub fn emit_member_defaulted(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cycle: u32,
+        covered: i128,
+        shortfall: i128,
+        rules: Vec<Symbol>,
+        defaulted_at: u64,
+    ) {
+        let event = MemberDefaulted {
+            group_id,
+            member,
+            cycle,
+            covered,
+            shortfall,
+            rules,
+            defaulted_at,
+        };
+        env.events().publish((Symbol::new(env, "member_defaulted"), group_id), event);
+    }
+    
+    pub fn emit_offline_contribution_attested(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        attestor: Address,
+        cycle: u32,
+        attested_at: u64,
+    ) {
+        let event = OfflineContributionAttested {
+            group_id,
+            member,
+            attestor,
+            cycle,
+            attested_at,
+        };
+        env.events().publish((Symbol::new(env, "offline_contribution_attested"), group_id), event);
     }
     
     pub fn emit_payout_executed(
@@ -168,7 +367,7 @@ impl EventEmitter {
             cycle,
             executed_at,
         };
-        env.events().publish(("payout_executed",), event);
+        env.events().publish((Symbol::new(env, "payout_executed"), group_id), event);
     }
     
     pub fn emit_group_completed(
@@ -186,7 +385,7 @@ impl EventEmitter {
             total_distributed,
             completed_at,
         };
-        env.events().publish(("group_completed",), event);
+        env.events().publish((Symbol::new(env, "group_completed"), group_id), event);
     }
     
     pub fn emit_group_status_changed(
@@ -204,7 +403,108 @@ impl EventEmitter {
             changed_by,
             changed_at,
         };
-        env.events().publish(("group_status_changed",), event);
+        env.events().publish((Symbol::new(env, "group_status_changed"), group_id), event);
+    }
+    
+    pub fn emit_group_activated(
+        env: &Env,
+        group_id: u64,
+        member_count: u32,
+        activated_at: u64,
+    ) {
+        let event = GroupActivated {
+            group_id,
+            member_count,
+            activated_at,
+        };
+        env.events().publish((Symbol::new(env, "group_activated"), group_id), event);
+    }
+    
+    pub fn emit_payout_claimed(
+        env: &Env,
+        group_id: u64,
+        recipient: Address,
+        cycle: u32,
+        amount: i128,
+        claimed_at: u64,
+    ) {
+        let event = PayoutClaimed {
+            group_id,
+            recipient,
+            cycle,
+            amount,
+            claimed_at,
+        };
+        env.events().publish((Symbol::new(env, "payout_claimed"), group_id), event);
+    }
+    
+    pub fn emit_deposit_locked(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        amount: i128,
+        locked_at: u64,
+    ) {
+        let event = DepositLocked {
+            group_id,
+            member,
+            amount,
+            locked_at,
+        };
+        env.events().publish((Symbol::new(env, "deposit_locked"), group_id), event);
+    }
+    
+    pub fn emit_deposit_slashed(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cycle: u32,
+        amount: i128,
+        slashed_at: u64,
+    ) {
+        let event = DepositSlashed {
+            group_id,
+            member,
+            cycle,
+            amount,
+            slashed_at,
+        };
+        env.events().publish((Symbol::new(env, "deposit_slashed"), group_id), event);
+    }
+    
+    pub fn emit_deposit_refunded(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        amount: i128,
+        refunded_at: u64,
+    ) {
+        let event = DepositRefunded {
+            group_id,
+            member,
+            amount,
+            refunded_at,
+        };
+        env.events().publish((Symbol::new(env, "deposit_refunded"), group_id), event);
+    }
+    
+    pub fn emit_config_updated(env: &Env, config: ContractConfig, updated_at: u64) {
+        let admin = config.admin.clone();
+        let event = ConfigUpdated {
+            admin: admin.clone(),
+            config,
+            updated_at,
+        };
+        env.events().publish((Symbol::new(env, "config_updated"), admin), event);
+    }
+
+    /// Publishes the context of an error about to be returned. The error
+    /// code is a third topic so failures can be filtered by code.
+    pub fn emit_error_context(env: &Env, error: StellarSaveError, context: ErrorContext) {
+        env.events().publish(
+            (Symbol::new(env, "error_context"), context.group_id, error.code()),
+            context,
+        );
     }
 }
 
@@ -247,6 +547,37 @@ mod tests {
         assert_eq!(event.member, member);
     }
 
+    #[test]
+    fn test_event_emitter_deposit_events() {
+        use soroban_sdk::testutils::Events as _;
+
+        let env = Env::default();
+        let contract_id = env.register_contract(None, crate::StellarSaveContract);
+        let member = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            EventEmitter::emit_deposit_locked(&env, 7, member.clone(), 50, 1234567890);
+            EventEmitter::emit_deposit_slashed(&env, 7, member.clone(), 2, 50, 1234567890);
+            EventEmitter::emit_deposit_refunded(&env, 7, member.clone(), 0, 1234567890);
+        });
+
+        assert_eq!(env.events().all().events().len(), 3);
+    }
+
+    #[test]
+    fn test_event_emitter_error_context() {
+        use soroban_sdk::testutils::Events as _;
+
+        let env = Env::default();
+        let contract_id = env.register_contract(None, crate::StellarSaveContract);
+        let member = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            let context = ErrorContext::new(7).with_cycle(1).with_address(&member);
+            EventEmitter::emit_error_context(&env, StellarSaveError::AlreadyContributed, context);
+        });
+
+        assert_eq!(env.events().all().events().len(), 1);
+    }
+
     #[test]
     fn test_event_emitter_group_created() {
         let env = Env::default();
@@ -0,0 +1,201 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+use crate::error::StellarSaveError;
+
+/// Maximum number of recipients a fee router may split fees between.
+pub const MAX_FEE_RECIPIENTS: u32 = 10;
+
+/// Maximum protocol fee, in basis points of the gross payout (10%).
+pub const MAX_FEE_BPS: u32 = 1_000;
+
+/// A single destination of protocol fees and its relative weight.
+///
+/// Typical recipients are the platform treasury, the partner that onboarded
+/// the group, the insurance fund and a community fund.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRecipient {
+    /// Address credited with this recipient's share.
+    pub recipient: Address,
+
+    /// Relative weight; the share is `weight / total_weight` of the fee.
+    /// Must be greater than 0.
+    pub weight: u32,
+}
+
+/// Admin-configured fee router.
+///
+/// A fee of `fee_bps` is taken from every gross payout and split between
+/// the recipients pro-rata to their weights. Shares accrue inside the contract
+/// and are withdrawn by each recipient separately.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRouter {
+    /// Fee charged on each payout, in basis points of the gross amount.
+    pub fee_bps: u32,
+
+    /// Fee recipients and their weights.
+    pub recipients: Vec<FeeRecipient>,
+}
+
+/// One recipient's share of a payout fee.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeShare {
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+/// How a cycle's gross pool is split between the recipient and fee recipients.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutBreakdown {
+    /// Total pool collected for the cycle.
+    pub gross_amount: i128,
+
+    /// Total fee taken from the pool.
+    pub fee_amount: i128,
+
+    /// Amount paid to the cycle recipient (`gross_amount - fee_amount`).
+    pub net_amount: i128,
+
+    /// Per-recipient split of `fee_amount`.
+    pub shares: Vec<FeeShare>,
+}
+
+impl FeeRouter {
+    /// Validates that the router configuration is sound.
+    ///
+    /// A router is valid when:
+    /// - `fee_bps` does not exceed `MAX_FEE_BPS`
+    /// - It has at most `MAX_FEE_RECIPIENTS` recipients, each with weight > 0
+    /// - It has at least one recipient whenever `fee_bps` is non-zero
+    pub fn validate(&self) -> bool {
+        if self.fee_bps > MAX_FEE_BPS || self.recipients.len() > MAX_FEE_RECIPIENTS {
+            return false;
+        }
+        if self.fee_bps > 0 && self.recipients.is_empty() {
+            return false;
+        }
+        self.recipients.iter().all(|r| r.weight > 0)
+    }
+
+    /// Returns the sum of all recipient weights.
+    pub fn total_weight(&self) -> u64 {
+        self.recipients.iter().map(|r| r.weight as u64).sum()
+    }
+
+    /// Splits a gross payout into the recipient's net amount and fee shares.
+    ///
+    /// Shares are rounded down; any rounding remainder goes to the first
+    /// recipient so the shares always sum exactly to the fee.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `gross_amount` - The cycle's total pool
+    ///
+    /// # Returns
+    /// * `Ok(PayoutBreakdown)` - The split
+    /// * `Err(StellarSaveError::InvalidAmount)` - If `gross_amount` is negative
+    /// * `Err(StellarSaveError::Overflow)` - If the calculation overflows
+    pub fn breakdown(&self, env: &Env, gross_amount: i128) -> Result<PayoutBreakdown, StellarSaveError> {
+        if gross_amount < 0 {
+            return Err(StellarSaveError::InvalidAmount);
+        }
+
+        let total_weight = self.total_weight();
+        let fee_amount = if total_weight == 0 {
+            0
+        } else {
+            gross_amount
+                .checked_mul(self.fee_bps as i128)
+                .ok_or(StellarSaveError::Overflow)?
+                / 10_000
+        };
+
+        let mut shares = Vec::new(env);
+        let mut distributed: i128 = 0;
+        for entry in self.recipients.iter() {
+            let amount = fee_amount
+                .checked_mul(entry.weight as i128)
+                .ok_or(StellarSaveError::Overflow)?
+                / total_weight as i128;
+            distributed += amount;
+            shares.push_back(FeeShare {
+                recipient: entry.recipient,
+                amount,
+            });
+        }
+
+        if let Some(mut first) = shares.get(0) {
+            first.amount += fee_amount - distributed;
+            shares.set(0, first);
+        }
+
+        Ok(PayoutBreakdown {
+            gross_amount,
+            fee_amount,
+            net_amount: gross_amount - fee_amount,
+            shares,
+        })
+    }
+}
+
+/// Returns the breakdown used when no fee router is configured: no fees.
+pub fn fee_free_breakdown(env: &Env, gross_amount: i128) -> PayoutBreakdown {
+    PayoutBreakdown {
+        gross_amount,
+        fee_amount: 0,
+        net_amount: gross_amount,
+        shares: Vec::new(env),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    fn router(env: &Env, fee_bps: u32, weights: &[u32]) -> FeeRouter {
+        let mut recipients = Vec::new(env);
+        for weight in weights.iter() {
+            recipients.push_back(FeeRecipient {
+                recipient: Address::generate(env),
+                weight: *weight,
+            });
+        }
+        FeeRouter { fee_bps, recipients }
+    }
+
+    #[test]
+    fn test_validate() {
+        let env = Env::default();
+        assert!(router(&env, 100, &[1, 2]).validate());
+        assert!(router(&env, 0, &[]).validate());
+        assert!(!router(&env, 100, &[]).validate());
+        assert!(!router(&env, 100, &[1, 0]).validate());
+        assert!(!router(&env, MAX_FEE_BPS + 1, &[1]).validate());
+    }
+
+    #[test]
+    fn test_breakdown_weighted_split() {
+        let env = Env::default();
+        // 2% of 10_000 = 200, split 3:1
+        let breakdown = router(&env, 200, &[3, 1]).breakdown(&env, 10_000).unwrap();
+
+        assert_eq!(breakdown.fee_amount, 200);
+        assert_eq!(breakdown.net_amount, 9_800);
+        assert_eq!(breakdown.shares.get(0).unwrap().amount, 150);
+        assert_eq!(breakdown.shares.get(1).unwrap().amount, 50);
+    }
+
+    #[test]
+    fn test_breakdown_remainder_to_first_recipient() {
+        let env = Env::default();
+        // 1% of 1_000 = 10, split three ways: 3 + 3 + 3, remainder 1
+        let breakdown = router(&env, 100, &[1, 1, 1]).breakdown(&env, 1_000).unwrap();
+
+        assert_eq!(breakdown.shares.get(0).unwrap().amount, 4);
+        assert_eq!(breakdown.shares.get(1).unwrap().amount, 3);
+        assert_eq!(breakdown.shares.get(2).unwrap().amount, 3);
+    }
+}
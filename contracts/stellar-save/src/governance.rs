@@ -0,0 +1,114 @@
+use soroban_sdk::{contracttype, Address};
+
+/// How long a proposal stays open for voting (7 days).
+pub const PROPOSAL_VOTING_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+/// A group decision members can propose and vote on.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalAction {
+    /// Cancel the group and refund members, as `cancel_group` does.
+    CancelGroup,
+
+    /// Approve handing a defaulted member's place, including their payout
+    /// position, over to a new address: `(defaulter, replacement)`. The
+    /// replacement completes the handover with `replace_member`.
+    ReplaceDefaulter(Address, Address),
+
+    /// Push the current and all later deadlines back by the given seconds.
+    ExtendDeadline(u64),
+}
+
+/// A member proposal within a group.
+///
+/// Every member has one vote. The proposal executes as soon as more than
+/// half of the group's members have voted for it, and lapses unexecuted
+/// once the voting period ends.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    /// Proposal ID, sequential within the group starting at 1.
+    pub id: u32,
+
+    /// Group the proposal belongs to.
+    pub group_id: u64,
+
+    /// Member who created the proposal.
+    pub proposer: Address,
+
+    /// What happens when the proposal passes.
+    pub action: ProposalAction,
+
+    /// Number of members who voted for the proposal.
+    pub votes_for: u32,
+
+    /// Number of members who voted against the proposal.
+    pub votes_against: u32,
+
+    /// Timestamp when the proposal was created.
+    pub created_at: u64,
+
+    /// Timestamp after which no more votes are accepted.
+    pub expires_at: u64,
+
+    /// Whether the proposal has passed and its action was applied.
+    pub executed: bool,
+}
+
+impl Proposal {
+    /// Returns true while the proposal can still be voted on.
+    pub fn is_open(&self, now: u64) -> bool {
+        !self.executed && now <= self.expires_at
+    }
+
+    /// Returns true once more than half of `member_count` members voted for
+    /// the proposal.
+    pub fn has_quorum(&self, member_count: u32) -> bool {
+        (self.votes_for as u64) * 2 > member_count as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    fn proposal(env: &Env) -> Proposal {
+        Proposal {
+            id: 1,
+            group_id: 1,
+            proposer: Address::generate(env),
+            action: ProposalAction::CancelGroup,
+            votes_for: 0,
+            votes_against: 0,
+            created_at: 100,
+            expires_at: 100 + PROPOSAL_VOTING_PERIOD,
+            executed: false,
+        }
+    }
+
+    #[test]
+    fn test_has_quorum_requires_majority() {
+        let env = Env::default();
+        let mut proposal = proposal(&env);
+
+        proposal.votes_for = 2;
+        assert!(!proposal.has_quorum(4));
+        assert!(proposal.has_quorum(3));
+
+        proposal.votes_for = 3;
+        assert!(proposal.has_quorum(4));
+    }
+
+    #[test]
+    fn test_is_open() {
+        let env = Env::default();
+        let mut proposal = proposal(&env);
+
+        assert!(proposal.is_open(100 + PROPOSAL_VOTING_PERIOD));
+        assert!(!proposal.is_open(101 + PROPOSAL_VOTING_PERIOD));
+
+        proposal.executed = true;
+        assert!(!proposal.is_open(100));
+    }
+}
@@ -1,5 +1,6 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN, Symbol, Vec};
 use core::fmt;
+use crate::status::StatusError;
 
 /// Represents the lifecycle states of a savings group.
 ///
@@ -9,8 +10,10 @@ use core::fmt;
 /// - Paused: Temporarily suspended (can be resumed)
 /// - Completed: All cycles finished successfully
 /// - Cancelled: Permanently terminated before completion
+/// - GracePeriod: Cycle deadline passed, waiting for late contributions
+/// - Defaulted: Missing contributions could not be covered
 #[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum GroupStatus {
     /// Group is created but not yet active.
     /// Waiting for minimum members or creator activation.
@@ -31,6 +34,16 @@ pub enum GroupStatus {
     /// Group was cancelled before completion.
     /// Funds should be returned to contributors.
     Cancelled,
+
+    /// The current cycle's deadline has passed with contributions missing.
+    /// Stragglers can still contribute late; payouts wait until the group
+    /// is Active again.
+    GracePeriod,
+
+    /// The grace period ran out with a shortfall that security deposits and
+    /// the insurance fund could not cover. The group can only be cancelled,
+    /// refunding members.
+    Defaulted,
 }
 
 impl GroupStatus {
@@ -38,8 +51,10 @@ impl GroupStatus {
     ///
     /// Valid transitions:
     /// - Pending → Active, Cancelled
-    /// - Active → Paused, Completed, Cancelled
+    /// - Active → Paused, Completed, Cancelled, GracePeriod
     /// - Paused → Active, Cancelled
+    /// - GracePeriod → Active, Defaulted, Cancelled
+    /// - Defaulted → Cancelled
     /// - Completed → (no transitions allowed)
     /// - Cancelled → (no transitions allowed)
     pub fn can_transition_to(&self, new_status: &GroupStatus) -> bool {
@@ -57,11 +72,20 @@ impl GroupStatus {
             (GroupStatus::Active, GroupStatus::Paused) => true,
             (GroupStatus::Active, GroupStatus::Completed) => true,
             (GroupStatus::Active, GroupStatus::Cancelled) => true,
+            (GroupStatus::Active, GroupStatus::GracePeriod) => true,
 
             // From Paused
             (GroupStatus::Paused, GroupStatus::Active) => true,
             (GroupStatus::Paused, GroupStatus::Cancelled) => true,
 
+            // From GracePeriod
+            (GroupStatus::GracePeriod, GroupStatus::Active) => true,
+            (GroupStatus::GracePeriod, GroupStatus::Defaulted) => true,
+            (GroupStatus::GracePeriod, GroupStatus::Cancelled) => true,
+
+            // From Defaulted
+            (GroupStatus::Defaulted, GroupStatus::Cancelled) => true,
+
             // Terminal states cannot transition to other states
             (GroupStatus::Completed, _) => false,
             (GroupStatus::Cancelled, _) => false,
@@ -72,8 +96,9 @@ impl GroupStatus {
     }
 
     /// Returns true if the group can accept contributions in this state.
+    /// During a grace period only the current cycle's stragglers contribute.
     pub fn accepts_contributions(&self) -> bool {
-        matches!(self, GroupStatus::Active)
+        matches!(self, GroupStatus::Active | GroupStatus::GracePeriod)
     }
 
     /// Returns true if the group can process payouts in this state.
@@ -95,10 +120,155 @@ impl fmt::Display for GroupStatus {
             GroupStatus::Paused => "Paused",
             GroupStatus::Completed => "Completed",
             GroupStatus::Cancelled => "Cancelled",
+            GroupStatus::GracePeriod => "GracePeriod",
+            GroupStatus::Defaulted => "Defaulted",
         };
         write!(f, "{}", status_str)
     }
 }
+
+/// How each cycle's payout recipient is chosen.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GroupMode {
+    /// Members are paid in the order of their fixed payout positions.
+    Rotation,
+
+    /// Members who have not been paid yet bid each cycle; the lowest bid wins
+    /// the pot and the discount is shared among the unpaid members.
+    Bidding,
+
+    /// Each cycle's recipient is drawn at payout time from the members who
+    /// have not been paid yet, using the Soroban PRNG.
+    Lottery,
+
+    /// No rotating payouts: contributions accumulate in each member's savings
+    /// balance, which they withdraw once the group matures.
+    SavingsPool,
+}
+
+/// Who may join a group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GroupVisibility {
+    /// Anyone may join with `join_group`.
+    Public,
+
+    /// Joining requires the creator's invite code, via `join_with_invite`.
+    Private,
+}
+
+/// Savings target of a savings pool.
+///
+/// Members may withdraw without penalty once their balance reaches
+/// `goal_amount`, the `target_date` passes or the group completes. Earlier
+/// withdrawals forfeit `early_exit_penalty_bps` of the balance to the other
+/// savers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SavingsGoal {
+    /// Balance each member is saving towards.
+    pub goal_amount: i128,
+
+    /// Timestamp after which withdrawals are penalty-free.
+    pub target_date: u64,
+
+    /// Share of the balance forfeited on early withdrawal, in basis points.
+    pub early_exit_penalty_bps: u32,
+}
+
+impl SavingsGoal {
+    /// Returns true once a member with `balance` may withdraw without penalty.
+    pub fn is_reached(&self, balance: i128, now: u64) -> bool {
+        balance >= self.goal_amount || now >= self.target_date
+    }
+
+    /// Penalty forfeited when withdrawing `balance` early, rounded down.
+    pub fn early_exit_penalty(&self, balance: i128) -> Option<i128> {
+        balance
+            .checked_mul(self.early_exit_penalty_bps as i128)
+            .map(|scaled| scaled / 10_000)
+    }
+}
+
+/// Reusable parameters for spawning identical groups with
+/// `create_group_from_template`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupTemplate {
+    /// Template ID, sequential starting at 1.
+    pub id: u64,
+
+    /// Address that created the template.
+    pub owner: Address,
+
+    /// Contribution per member per cycle.
+    pub contribution_amount: i128,
+
+    /// Length of a cycle in seconds.
+    pub cycle_duration: u64,
+
+    /// Maximum number of members.
+    pub max_members: u32,
+
+    /// Stellar Asset Contract groups contribute and pay out in.
+    pub token: Address,
+}
+
+/// One paid-out cycle of an archived group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleSummary {
+    /// Cycle number (0-indexed).
+    pub cycle: u32,
+
+    /// Member who received the cycle's payout.
+    pub recipient: Address,
+
+    /// Net amount paid out, in stroops.
+    pub payout_amount: i128,
+
+    /// Total contributed to the cycle, in stroops.
+    pub total_contributed: i128,
+
+    /// Number of members counted as contributors.
+    pub contributor_count: u32,
+
+    /// Timestamp of the payout.
+    pub paid_at: u64,
+}
+
+/// Summary of a completed group kept by `archive_group` in place of its
+/// per-cycle contribution and payout records.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupArchive {
+    /// Archived group.
+    pub group_id: u64,
+
+    /// Paid-out cycles, in cycle order.
+    pub cycles: Vec<CycleSummary>,
+
+    /// Total contributed over all cycles, in stroops.
+    pub total_contributed: i128,
+
+    /// Total paid out over all cycles, in stroops.
+    pub total_paid_out: i128,
+
+    /// Timestamp when the group was archived.
+    pub archived_at: u64,
+}
+
+/// Maximum number of custom rule identifiers a group may carry.
+pub const MAX_GROUP_RULES: u32 = 10;
+
+/// Maximum number of co-admins a group creator may designate.
+pub const MAX_GROUP_ADMINS: u32 = 5;
+
+/// How long members of an auto-restarted group have to opt out before it
+/// can be activated (3 days).
+pub const RESTART_OPT_OUT_WINDOW: u64 = 3 * 24 * 60 * 60;
+
 /// Core Group data structure representing a rotational savings group (ROSCA).
 /// 
 /// A Group manages the configuration and state of a savings circle where members
@@ -146,15 +316,9 @@ pub struct Group {
     /// When current_cycle reaches max_members, the group is complete.
     pub current_cycle: u32,
 
-    /// Whether the group is currently active and accepting contributions.
-    /// Set to false when:
-    /// - Group is paused by admin/creator
-    /// - All cycles are complete
-    /// - Group encounters an error state
-    pub is_active: bool,
-
-    /// Status of the group (Active or Completed).
-    /// Replaces is_active for more explicit state management.
+    /// Lifecycle status of the group.
+    /// This is the only place the status is stored; it changes through
+    /// `set_status`, which enforces `GroupStatus::validate_transition`.
     pub status: GroupStatus,
 
     /// Timestamp when the group was created (Unix timestamp in seconds).
@@ -168,6 +332,100 @@ pub struct Group {
     /// Used for tracking when the first cycle started.
     /// Only set when started is true.
     pub started_at: u64,
+
+    /// Whether the member roster is hidden from public queries.
+    /// When true, endpoints that expose member addresses or per-member data
+    /// require authorization from the creator or a current member.
+    /// Aggregate statistics (member count, pool totals) remain public.
+    pub private_roster: bool,
+
+    /// Whether payout positions were explicitly assigned by the creator.
+    /// Members joining afterwards are appended to the end of the assigned order.
+    pub assignments_finalized: bool,
+
+    /// Seconds after a cycle deadline during which late contributions are
+    /// still accepted. 0 disables late contributions.
+    pub grace_period: u64,
+
+    /// Fee charged on contributions made during the grace period, in basis
+    /// points of the contribution amount. The fee is added to the cycle pool.
+    pub late_fee_bps: u32,
+
+    /// Collateral each member locks in the contract when joining, in stroops.
+    /// Refunded on clean completion and slashed toward the cycle pool if the
+    /// member misses a contribution. 0 disables the requirement.
+    pub security_deposit: i128,
+
+    /// Insurance premium charged on top of every contribution, in basis
+    /// points of the contribution amount. Snapshotted from the contract
+    /// config at creation; 0 means the group has no insurance fund.
+    pub insurance_bps: u32,
+
+    /// How each cycle's recipient is chosen. Defaults to `Rotation`.
+    pub mode: GroupMode,
+
+    /// Minimum reputation score required to join. 0 lets anyone join.
+    pub min_reputation: u32,
+
+    /// Whether joining requires an invite code. Defaults to `Public`.
+    pub visibility: GroupVisibility,
+
+    /// Timestamp when the group was last paused (Unix timestamp in seconds).
+    /// 0 while the group is not paused.
+    pub paused_at: u64,
+
+    /// Total seconds the group has spent paused, plus any deadline
+    /// extensions voted by members.
+    /// Contribution deadlines are shifted by this amount so paused time
+    /// does not count against members.
+    pub total_paused_duration: u64,
+
+    /// Whether new members may still join once the group is Active by
+    /// catching up on the cycles they missed.
+    pub late_join: bool,
+
+    /// Whether the group starts a new round with the same members once it
+    /// completes.
+    pub auto_restart: bool,
+
+    /// Number of installments each payout is released in, one per cycle
+    /// duration. 0 pays every payout as a lump sum.
+    pub payout_vesting_cycles: u32,
+
+    /// Whether members waiting for their turn may take an early payout
+    /// advance from the insurance fund.
+    pub advances_enabled: bool,
+
+    /// Interest charged on advances, in basis points of the amount advanced.
+    pub advance_interest_bps: u32,
+
+    /// Whether contributions waiting for the cycle payout are deposited in
+    /// the token's yield adapter.
+    pub yield_enabled: bool,
+
+    /// Contribution per cycle in USD, with `USD_DECIMALS` decimals. When set,
+    /// `contribution_amount` is recomputed from the price oracle at the
+    /// first contribution of every cycle. 0 keeps the amount fixed in tokens.
+    pub usd_contribution: i128,
+
+    /// Whether members may contribute in another asset swapped to the
+    /// group's token through the DEX router.
+    pub swaps_enabled: bool,
+
+    /// Whether members who contributed on time in every cycle are placed
+    /// first in the payout order of the next round.
+    pub streak_priority: bool,
+
+    /// Limit on the number of groups a joining member may belong to,
+    /// overriding the contract's `max_concurrent_groups`; 0 lifts the limit.
+    pub concurrent_group_limit: Option<u32>,
+
+    /// Optional display name.
+    pub name: Option<Symbol>,
+
+    /// Optional hash (e.g. an IPFS digest) of richer off-chain metadata such
+    /// as a description.
+    pub metadata_hash: Option<BytesN<32>>,
 }
 
 impl Group {
@@ -237,14 +495,71 @@ impl Group {
             min_members,
             member_count: 0,
             current_cycle: 0,
-            is_active: true,
-            status: GroupStatus::Active,
+            status: GroupStatus::Pending,
             created_at,
             started: false,
             started_at: 0,
+            private_roster: false,
+            assignments_finalized: false,
+            grace_period: 0,
+            late_fee_bps: 0,
+            security_deposit: 0,
+            insurance_bps: 0,
+            mode: GroupMode::Rotation,
+            min_reputation: 0,
+            visibility: GroupVisibility::Public,
+            paused_at: 0,
+            total_paused_duration: 0,
+            late_join: false,
+            auto_restart: false,
+            payout_vesting_cycles: 0,
+            advances_enabled: false,
+            advance_interest_bps: 0,
+            yield_enabled: false,
+            usd_contribution: 0,
+            swaps_enabled: false,
+            streak_priority: false,
+            concurrent_group_limit: None,
+            name: None,
+            metadata_hash: None,
         }
     }
 
+    /// Creates the next round of a recurring group: a new group with the same
+    /// creator and settings but no members and no progress.
+    pub fn next_round(&self, id: u64, created_at: u64) -> Self {
+        let mut next = Self::new(
+            id,
+            self.creator.clone(),
+            self.contribution_amount,
+            self.cycle_duration,
+            self.max_members,
+            self.min_members,
+            created_at,
+        );
+        next.private_roster = self.private_roster;
+        next.grace_period = self.grace_period;
+        next.late_fee_bps = self.late_fee_bps;
+        next.security_deposit = self.security_deposit;
+        next.insurance_bps = self.insurance_bps;
+        next.mode = self.mode.clone();
+        next.min_reputation = self.min_reputation;
+        next.visibility = self.visibility.clone();
+        next.late_join = self.late_join;
+        next.auto_restart = self.auto_restart;
+        next.payout_vesting_cycles = self.payout_vesting_cycles;
+        next.advances_enabled = self.advances_enabled;
+        next.advance_interest_bps = self.advance_interest_bps;
+        next.yield_enabled = self.yield_enabled;
+        next.usd_contribution = self.usd_contribution;
+        next.swaps_enabled = self.swaps_enabled;
+        next.streak_priority = self.streak_priority;
+        next.concurrent_group_limit = self.concurrent_group_limit;
+        next.name = self.name.clone();
+        next.metadata_hash = self.metadata_hash.clone();
+        next
+    }
+
     /// Checks if the group has completed all cycles.
     /// A group is complete when current_cycle equals max_members
     /// or when status is Completed.
@@ -252,6 +567,21 @@ impl Group {
         self.current_cycle >= self.max_members || self.status == GroupStatus::Completed
     }
 
+    /// Moves the group to a new status if `GroupStatus::validate_transition`
+    /// allows it.
+    /// 
+    /// The contract persists the group and updates its status indexes after
+    /// a successful transition, in `transition_group_status`.
+    /// 
+    /// # Returns
+    /// * `Err(StatusError)` - The transition is not allowed; the status is
+    ///   left unchanged
+    pub fn set_status(&mut self, status: GroupStatus) -> Result<(), StatusError> {
+        self.status.validate_transition(&status)?;
+        self.status = status;
+        Ok(())
+    }
+
     /// Marks the group as completed.
     /// This should be called after verifying all payouts have been made.
     /// Emits a GroupEvent::Completed event.
@@ -260,11 +590,10 @@ impl Group {
     /// * `env` - Soroban environment for event emission
     /// 
     /// # Panics
-    /// Panics if the group is already complete.
+    /// Panics if the group is already complete or not Active.
     pub fn complete(&mut self, env: &soroban_sdk::Env) {
         assert!(!self.is_complete(), "group is already complete");
-        self.status = GroupStatus::Completed;
-        self.is_active = false;
+        assert!(self.set_status(GroupStatus::Completed).is_ok(), "only an active group can complete");
         
         // Emit completion event
         Self::emit_completed_event(env, self.id);
@@ -284,7 +613,8 @@ impl Group {
 
     /// Advances to the next cycle.
     /// Should be called after a successful payout.
-    /// Emits a GroupEvent::Completed event when the group becomes complete.
+    /// Emits a GroupEvent::Completed event when the group becomes complete;
+    /// the contract then moves its status to Completed.
     /// 
     /// # Arguments
     /// * `env` - Soroban environment for event emission
@@ -295,29 +625,54 @@ impl Group {
         assert!(!self.is_complete(), "group is already complete");
         self.current_cycle += 1;
         
-        // Mark as complete if we've reached the final cycle
         if self.is_complete() {
-            self.status = GroupStatus::Completed;
-            self.is_active = false;
-            
             // Emit completion event
             Self::emit_completed_event(env, self.id);
         }
     }
 
-    /// Deactivates the group, preventing further contributions.
-    pub fn deactivate(&mut self) {
-        self.is_active = false;
+    /// Records the start of a pause, freezing the group's deadlines until it
+    /// is resumed. The status moves to Paused separately, with `set_status`.
+    /// 
+    /// # Arguments
+    /// * `timestamp` - Current timestamp when the pause starts
+    pub fn pause(&mut self, timestamp: u64) {
+        self.paused_at = timestamp;
     }
 
-    /// Reactivates the group if it's not complete.
+    /// Records the end of a pause, adding the time spent paused to
+    /// `total_paused_duration`. The status moves back to Active separately,
+    /// with `set_status`.
     /// 
-    /// # Panics
-    /// Panics if attempting to reactivate a completed group.
-    pub fn reactivate(&mut self) {
-        assert!(!self.is_complete(), "cannot reactivate a completed group");
-        self.is_active = true;
-        self.status = GroupStatus::Active;
+    /// # Arguments
+    /// * `timestamp` - Current timestamp when the group resumes
+    pub fn resume(&mut self, timestamp: u64) {
+        let paused_for = timestamp.saturating_sub(self.paused_at);
+        self.total_paused_duration = self.total_paused_duration.saturating_add(paused_for);
+        self.paused_at = 0;
+    }
+
+    /// Returns the seconds the group has spent paused as of `now`, including
+    /// a pause that is still in progress.
+    pub fn paused_duration(&self, now: u64) -> u64 {
+        let ongoing = if self.status == GroupStatus::Paused {
+            now.saturating_sub(self.paused_at)
+        } else {
+            0
+        };
+        self.total_paused_duration.saturating_add(ongoing)
+    }
+
+    /// Returns the contribution deadline of `cycle` as of `now`.
+    /// 
+    /// The deadline is `started_at + (cycle + 1) * cycle_duration`, shifted by
+    /// the time the group has spent paused. Returns `None` on overflow.
+    pub fn cycle_deadline(&self, cycle: u32, now: u64) -> Option<u64> {
+        (cycle as u64)
+            .checked_add(1)?
+            .checked_mul(self.cycle_duration)?
+            .checked_add(self.started_at)?
+            .checked_add(self.paused_duration(now))
     }
 
     /// Activates the group (starts the first cycle) once minimum members have joined.
@@ -402,9 +757,16 @@ mod tests {
         assert_eq!(group.min_members, 2);
         assert_eq!(group.member_count, 0);
         assert_eq!(group.current_cycle, 0);
-        assert_eq!(group.is_active, true);
-        assert_eq!(group.status, GroupStatus::Active);
+        assert_eq!(group.status, GroupStatus::Pending);
         assert_eq!(group.created_at, 1234567890);
+        assert!(!group.private_roster);
+        assert!(!group.assignments_finalized);
+        assert_eq!(group.grace_period, 0);
+        assert_eq!(group.late_fee_bps, 0);
+        assert_eq!(group.security_deposit, 0);
+        assert_eq!(group.insurance_bps, 0);
+        assert_eq!(group.mode, GroupMode::Rotation);
+        assert_eq!(group.min_reputation, 0);
     }
 
     #[test]
@@ -476,23 +838,18 @@ mod tests {
         let mut group = Group::new(1, creator, 10_000_000, 604800, 3, 2, 1234567890);
         
         assert_eq!(group.current_cycle, 0);
-        assert!(group.is_active);
-        assert_eq!(group.status, GroupStatus::Active);
         
         group.advance_cycle(&env);
         assert_eq!(group.current_cycle, 1);
-        assert!(group.is_active);
-        assert_eq!(group.status, GroupStatus::Active);
+        assert!(!group.is_complete());
         
         group.advance_cycle(&env);
         assert_eq!(group.current_cycle, 2);
-        assert!(group.is_active);
-        assert_eq!(group.status, GroupStatus::Active);
+        assert!(!group.is_complete());
         
         group.advance_cycle(&env);
         assert_eq!(group.current_cycle, 3);
-        assert!(!group.is_active); // Auto-deactivated when complete
-        assert_eq!(group.status, GroupStatus::Completed); // Status set to Completed
+        assert!(group.is_complete());
     }
 
     #[test]
@@ -508,22 +865,65 @@ mod tests {
     }
 
     #[test]
-    fn test_deactivate_reactivate() {
+    fn test_set_status_enforces_transitions() {
         let env = Env::default();
         let creator = Address::generate(&env);
         
         let mut group = Group::new(1, creator, 10_000_000, 604800, 3, 2, 1234567890);
         
-        assert!(group.is_active);
-        assert_eq!(group.status, GroupStatus::Active);
+        assert_eq!(
+            group.set_status(GroupStatus::Paused),
+            Err(StatusError::InvalidTransition {
+                from: GroupStatus::Pending,
+                to: GroupStatus::Paused,
+            })
+        );
+        assert_eq!(group.status, GroupStatus::Pending);
+        
+        assert!(group.set_status(GroupStatus::Active).is_ok());
+        assert!(group.set_status(GroupStatus::Paused).is_ok());
+        assert!(group.set_status(GroupStatus::Cancelled).is_ok());
+        assert_eq!(group.set_status(GroupStatus::Active), Err(StatusError::AlreadyCancelled));
+        assert_eq!(group.status, GroupStatus::Cancelled);
+    }
+
+    #[test]
+    fn test_pause_resume_accumulates_duration() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        
+        let mut group = Group::new(1, creator, 10_000_000, 604800, 3, 2, 1000);
         
-        group.deactivate();
-        assert!(!group.is_active);
-        assert_eq!(group.status, GroupStatus::Active); // Status remains Active when just deactivated
+        group.pause(2000);
+        assert_eq!(group.paused_at, 2000);
         
-        group.reactivate();
-        assert!(group.is_active);
-        assert_eq!(group.status, GroupStatus::Active);
+        group.resume(2500);
+        group.pause(3000);
+        group.resume(3100);
+        assert_eq!(group.paused_at, 0);
+        assert_eq!(group.total_paused_duration, 600);
+    }
+
+    #[test]
+    fn test_cycle_deadline_shifts_with_pause() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        
+        let mut group = Group::new(1, creator, 10_000_000, 100, 3, 2, 0);
+        group.member_count = 2;
+        group.activate(1000);
+        assert_eq!(group.cycle_deadline(0, 1000), Some(1100));
+        assert_eq!(group.cycle_deadline(2, 1000), Some(1300));
+        
+        // A pause in progress keeps pushing deadlines back
+        group.set_status(GroupStatus::Active).unwrap();
+        group.pause(1050);
+        group.set_status(GroupStatus::Paused).unwrap();
+        assert_eq!(group.cycle_deadline(0, 1080), Some(1130));
+        
+        group.resume(1090);
+        group.set_status(GroupStatus::Active).unwrap();
+        assert_eq!(group.cycle_deadline(0, 5000), Some(1140));
     }
 
     #[test]
@@ -533,9 +933,9 @@ mod tests {
         
         let mut group = Group::new(1, creator, 10_000_000, 604800, 3, 2, 1234567890);
         
-        // Group starts as Active
-        assert_eq!(group.status, GroupStatus::Active);
-        assert!(group.is_active);
+        // Group starts Pending and runs once activated
+        assert_eq!(group.status, GroupStatus::Pending);
+        group.set_status(GroupStatus::Active).unwrap();
         assert!(!group.is_complete());
         
         // Complete the group manually
@@ -543,7 +943,6 @@ mod tests {
         
         // Verify group is marked as completed
         assert_eq!(group.status, GroupStatus::Completed);
-        assert!(!group.is_active);
         assert!(group.is_complete());
     }
 
@@ -588,22 +987,9 @@ mod tests {
         group.advance_cycle(&env); // cycle 2
         group.advance_cycle(&env); // cycle 3 - complete
         
-        // Verify group is complete
+        // Verify group is complete; the contract moves the status itself
         assert!(group.is_complete());
-        assert_eq!(group.status, GroupStatus::Completed);
-        assert!(!group.is_active);
-    }
-
-    #[test]
-    #[should_panic(expected = "cannot reactivate a completed group")]
-    fn test_reactivate_completed_group() {
-        let env = Env::default();
-        let creator = Address::generate(&env);
-        
-        let mut group = Group::new(1, creator, 10_000_000, 604800, 2, 2, 1234567890);
-        group.current_cycle = 2;
-        
-        group.reactivate(); // Should panic
+        assert_eq!(group.status, GroupStatus::Pending);
     }
 
     #[test]
@@ -657,6 +1043,21 @@ mod tests {
         assert!(!GroupStatus::Cancelled.can_transition_to(&GroupStatus::Paused));
         assert!(!GroupStatus::Cancelled.can_transition_to(&GroupStatus::Completed));
 
+        // Test valid transitions from GracePeriod
+        assert!(GroupStatus::Active.can_transition_to(&GroupStatus::GracePeriod));
+        assert!(GroupStatus::GracePeriod.can_transition_to(&GroupStatus::Active));
+        assert!(GroupStatus::GracePeriod.can_transition_to(&GroupStatus::Defaulted));
+        assert!(GroupStatus::GracePeriod.can_transition_to(&GroupStatus::Cancelled));
+        assert!(!GroupStatus::GracePeriod.can_transition_to(&GroupStatus::Paused));
+        assert!(!GroupStatus::GracePeriod.can_transition_to(&GroupStatus::Completed));
+
+        // Test Defaulted groups can only be cancelled
+        assert!(GroupStatus::Defaulted.can_transition_to(&GroupStatus::Cancelled));
+        assert!(!GroupStatus::Defaulted.can_transition_to(&GroupStatus::Active));
+        assert!(!GroupStatus::Defaulted.can_transition_to(&GroupStatus::GracePeriod));
+        assert!(!GroupStatus::Pending.can_transition_to(&GroupStatus::Defaulted));
+        assert!(!GroupStatus::Active.can_transition_to(&GroupStatus::Defaulted));
+
         // Test same state transitions are always valid
         assert!(GroupStatus::Pending.can_transition_to(&GroupStatus::Pending));
         assert!(GroupStatus::Active.can_transition_to(&GroupStatus::Active));
@@ -672,6 +1073,8 @@ mod tests {
         assert!(!GroupStatus::Paused.accepts_contributions());
         assert!(!GroupStatus::Completed.accepts_contributions());
         assert!(!GroupStatus::Cancelled.accepts_contributions());
+        assert!(GroupStatus::GracePeriod.accepts_contributions());
+        assert!(!GroupStatus::Defaulted.accepts_contributions());
     }
 
     #[test]
@@ -681,6 +1084,44 @@ mod tests {
         assert!(!GroupStatus::Paused.can_process_payouts());
         assert!(!GroupStatus::Completed.can_process_payouts());
         assert!(!GroupStatus::Cancelled.can_process_payouts());
+        assert!(!GroupStatus::GracePeriod.can_process_payouts());
+        assert!(!GroupStatus::Defaulted.can_process_payouts());
+    }
+
+    #[test]
+    fn test_next_round_keeps_settings_and_resets_progress() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        let mut group = Group::new(1, creator.clone(), 100, 3600, 5, 2, 1000);
+        group.security_deposit = 50;
+        group.auto_restart = true;
+        group.member_count = 5;
+        group.started = true;
+        group.current_cycle = 5;
+
+        let next = group.next_round(2, 9000);
+        assert_eq!(next.id, 2);
+        assert_eq!(next.creator, creator);
+        assert_eq!(next.security_deposit, 50);
+        assert!(next.auto_restart);
+        assert_eq!(next.member_count, 0);
+        assert_eq!(next.current_cycle, 0);
+        assert!(!next.started);
+        assert_eq!(next.created_at, 9000);
+    }
+
+    #[test]
+    fn test_savings_goal() {
+        let goal = SavingsGoal {
+            goal_amount: 500,
+            target_date: 1_000,
+            early_exit_penalty_bps: 1_000,
+        };
+
+        assert!(!goal.is_reached(499, 999));
+        assert!(goal.is_reached(500, 999));
+        assert!(goal.is_reached(0, 1_000));
+        assert_eq!(goal.early_exit_penalty(255), Some(25));
     }
 
     #[test]
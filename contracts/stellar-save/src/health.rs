@@ -0,0 +1,130 @@
+use soroban_sdk::contracttype;
+
+/// Score points for contributing on time, scaled by the on-time rate.
+pub const ON_TIME_WEIGHT: u32 = 40;
+
+/// Score points for a default-free record, scaled down by the default rate.
+pub const DEFAULT_WEIGHT: u32 = 25;
+
+/// Score points for keeping cycles closing on schedule; lost in full once the
+/// group is `MAX_CYCLES_BEHIND` cycles behind.
+pub const SCHEDULE_WEIGHT: u32 = 20;
+
+/// Score points for security deposits covering a full cycle's contributions.
+pub const DEPOSIT_WEIGHT: u32 = 15;
+
+/// Cycles behind schedule at which the schedule points are all lost.
+pub const MAX_CYCLES_BEHIND: u32 = 2;
+
+/// Composite risk indicator of a group, for warning prospective members.
+///
+/// The score runs from 0 (risky) to 100 (healthy). A group without any
+/// history yet scores full marks on contributions and defaults.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupHealth {
+    /// Group the score belongs to.
+    pub group_id: u64,
+
+    /// Composite score from 0 to 100.
+    pub score: u32,
+
+    /// Share of member contributions made before the deadline, in basis points.
+    pub on_time_bps: u32,
+
+    /// Cycles members have defaulted on, summed over all members.
+    pub defaults: u32,
+
+    /// Cycles that have ended on ledger time but not been closed yet.
+    pub cycles_behind: u32,
+
+    /// Security deposits held, in basis points of one cycle's contributions
+    /// from every member; capped at 10,000.
+    pub deposit_coverage_bps: u32,
+}
+
+impl GroupHealth {
+    /// Derives the health indicators and score from a group's record.
+    ///
+    /// # Arguments
+    /// * `on_time` - Contributions made before the deadline
+    /// * `late` - Contributions made during a grace period
+    /// * `defaults` - Cycles members defaulted on
+    /// * `cycles_behind` - Ended cycles not closed yet
+    /// * `deposits` - Security deposits held
+    /// * `cycle_obligation` - One cycle's contributions from every member
+    pub fn compute(
+        group_id: u64,
+        on_time: u32,
+        late: u32,
+        defaults: u32,
+        cycles_behind: u32,
+        deposits: i128,
+        cycle_obligation: i128,
+    ) -> Self {
+        let on_time_bps = ratio_bps(on_time as i128, on_time as i128 + late as i128, 10_000);
+        let obligations = on_time as i128 + late as i128 + defaults as i128;
+        let kept_bps = ratio_bps(obligations - defaults as i128, obligations, 10_000);
+        let deposit_coverage_bps = ratio_bps(deposits, cycle_obligation, 0);
+        let schedule = SCHEDULE_WEIGHT
+            * MAX_CYCLES_BEHIND.saturating_sub(cycles_behind)
+            / MAX_CYCLES_BEHIND;
+
+        let score = ON_TIME_WEIGHT * on_time_bps / 10_000
+            + DEFAULT_WEIGHT * kept_bps / 10_000
+            + schedule
+            + DEPOSIT_WEIGHT * deposit_coverage_bps / 10_000;
+
+        Self {
+            group_id,
+            score,
+            on_time_bps,
+            defaults,
+            cycles_behind,
+            deposit_coverage_bps,
+        }
+    }
+}
+
+/// Returns `part / whole` in basis points, capped at 10,000, or `empty` when
+/// `whole` is not positive.
+fn ratio_bps(part: i128, whole: i128, empty: u32) -> u32 {
+    if whole <= 0 {
+        return empty;
+    }
+    (part.max(0).saturating_mul(10_000) / whole).min(10_000) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_group_without_deposits() {
+        let health = GroupHealth::compute(1, 0, 0, 0, 0, 0, 300);
+
+        assert_eq!(health.on_time_bps, 10_000);
+        assert_eq!(health.deposit_coverage_bps, 0);
+        assert_eq!(health.score, 85);
+    }
+
+    #[test]
+    fn test_score_components() {
+        // 3 of 4 contributions on time, 1 default in 5 obligations,
+        // 1 cycle behind and deposits covering half a cycle
+        let health = GroupHealth::compute(1, 3, 1, 1, 1, 150, 300);
+
+        assert_eq!(health.on_time_bps, 7_500);
+        assert_eq!(health.deposit_coverage_bps, 5_000);
+        assert_eq!(health.score, 30 + 20 + 10 + 7);
+    }
+
+    #[test]
+    fn test_score_floors() {
+        let health = GroupHealth::compute(1, 0, 2, 2, 5, 0, 300);
+
+        // Only the half of obligations kept earns points
+        assert_eq!(health.on_time_bps, 0);
+        assert_eq!(health.score, 12);
+    }
+}
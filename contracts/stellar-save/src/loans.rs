@@ -0,0 +1,126 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Maximum advance a member can take, in basis points of the cycle pool (50%).
+pub const MAX_ADVANCE_BPS: u32 = 5_000;
+
+/// Maximum principal a group may have lent out at once, in basis points of
+/// its insurance reserve, i.e. the insurance fund plus what is lent (25%).
+pub const MAX_LENT_INSURANCE_BPS: u32 = 2_500;
+
+/// An early payout advance lent to a member waiting for their turn.
+///
+/// Advances are lent from the group's insurance fund, so they never touch a
+/// cycle pool. Only a fraction of the fund may be lent out at once (see
+/// `MAX_LENT_INSURANCE_BPS`), so it can still cover defaults. The borrower repays principal plus interest over the cycles
+/// left before their own turn; whatever is still owed then is deducted from
+/// their payout. Principal returns to the insurance fund and interest is
+/// shared among the other members.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Loan {
+    /// Member who took the advance.
+    pub borrower: Address,
+
+    /// Group the advance was taken in.
+    pub group_id: u64,
+
+    /// Amount advanced, in stroops.
+    pub principal: i128,
+
+    /// Interest owed on top of the principal, in stroops.
+    pub interest: i128,
+
+    /// Total repaid so far, principal and interest together.
+    pub repaid: i128,
+
+    /// Part of `repaid` that was interest.
+    pub interest_paid: i128,
+
+    /// Number of cycles the repayment is spread over.
+    pub installments: u32,
+
+    /// Cycle the advance was taken in.
+    pub issued_cycle: u32,
+
+    /// Timestamp when the advance was taken.
+    pub issued_at: u64,
+}
+
+impl Loan {
+    /// Returns the amount still owed, principal and interest together.
+    pub fn outstanding(&self) -> i128 {
+        self.principal + self.interest - self.repaid
+    }
+
+    /// Returns the principal still owed.
+    pub fn principal_outstanding(&self) -> i128 {
+        self.principal - (self.repaid - self.interest_paid)
+    }
+
+    /// Returns the repayment due each cycle, rounded up.
+    pub fn installment_amount(&self) -> i128 {
+        let owed = self.principal + self.interest;
+        if self.installments == 0 {
+            return owed;
+        }
+        let installments = self.installments as i128;
+        (owed + installments - 1) / installments
+    }
+
+    /// Applies a repayment of at most `outstanding()`.
+    ///
+    /// # Returns
+    /// `(principal, interest)` - how the repayment splits between principal
+    /// and interest; interest is repaid pro rata so the last repayment
+    /// settles both exactly
+    pub fn apply_repayment(&mut self, amount: i128) -> (i128, i128) {
+        let amount = amount.min(self.outstanding()).max(0);
+        self.repaid += amount;
+        let interest = if self.outstanding() == 0 {
+            self.interest - self.interest_paid
+        } else {
+            let due = self.interest * self.repaid / (self.principal + self.interest);
+            (due - self.interest_paid).max(0)
+        };
+        self.interest_paid += interest;
+        (amount - interest, interest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    fn loan(env: &Env) -> Loan {
+        Loan {
+            borrower: Address::generate(env),
+            group_id: 1,
+            principal: 1_000,
+            interest: 100,
+            repaid: 0,
+            interest_paid: 0,
+            installments: 3,
+            issued_cycle: 0,
+            issued_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_installment_amount_rounds_up() {
+        let env = Env::default();
+        assert_eq!(loan(&env).installment_amount(), 367);
+    }
+
+    #[test]
+    fn test_apply_repayment_settles_interest_exactly() {
+        let env = Env::default();
+        let mut loan = loan(&env);
+
+        assert_eq!(loan.apply_repayment(367), (334, 33));
+        assert_eq!(loan.apply_repayment(367), (334, 33));
+        assert_eq!(loan.apply_repayment(500), (332, 34));
+        assert_eq!(loan.outstanding(), 0);
+        assert_eq!(loan.interest_paid, 100);
+    }
+}
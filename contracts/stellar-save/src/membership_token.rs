@@ -0,0 +1,15 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Cross-contract interface of the companion membership token.
+///
+/// The token is non-transferable: the contract mints one for each group an
+/// address joins and burns it when they leave, are replaced, or the group
+/// ends, so wallets and other dapps can show and gate on live memberships.
+#[contractclient(name = "MembershipTokenClient")]
+pub trait MembershipToken {
+    /// Mints the token for membership of `group_id` to `to`.
+    fn mint(env: Env, to: Address, group_id: u64);
+
+    /// Burns the token for membership of `group_id` held by `from`.
+    fn burn(env: Env, from: Address, group_id: u64);
+}
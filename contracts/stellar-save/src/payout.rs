@@ -1,5 +1,8 @@
 ﻿use soroban_sdk::{contracttype, Address};
 
+/// Seconds a credited payout stays claimable before it may be escheated (1 year).
+pub const CLAIM_WINDOW: u64 = 365 * 24 * 60 * 60;
+
 /// Payout Record structure for tracking payout events in rotational savings groups.
 /// 
 /// Each payout represents a distribution of pooled funds to a member during their
@@ -28,6 +31,46 @@ pub struct PayoutRecord {
     /// Timestamp when the payout was executed (Unix timestamp in seconds).
     /// Used for tracking payout timing and audit purposes.
     pub timestamp: u64,
+
+    /// How the recipient was selected: `Scheduled` for rotation and bidding
+    /// payouts, or the lottery draw that picked them in lottery groups.
+    pub selection: RecipientSelection,
+
+    /// Final total contributed to the cycle, in stroops.
+    /// Snapshot of the cycle's temporary running total taken at payout.
+    pub cycle_total: i128,
+
+    /// Final number of members counted as contributors for the cycle.
+    /// Snapshot of the cycle's temporary contributor count taken at payout.
+    pub contributor_count: u32,
+}
+
+/// How a payout's recipient was selected.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RecipientSelection {
+    /// By payout position or winning bid.
+    Scheduled,
+
+    /// By a lottery draw among the unpaid members.
+    Lottery(LotteryDraw),
+}
+
+/// Audit record of a lottery draw selecting a cycle's recipient.
+/// 
+/// The recipient is `candidates[drawn_index]`, where candidates are the
+/// members who had not been paid yet, in join order.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LotteryDraw {
+    /// Number of members eligible for the draw.
+    pub candidate_count: u32,
+
+    /// Index drawn by the Soroban PRNG, in `0..candidate_count`.
+    pub drawn_index: u32,
+
+    /// Ledger sequence the draw happened in.
+    pub ledger_sequence: u32,
 }
 
 impl PayoutRecord {
@@ -59,9 +102,33 @@ impl PayoutRecord {
             cycle_number,
             amount,
             timestamp,
+            selection: RecipientSelection::Scheduled,
+            cycle_total: 0,
+            contributor_count: 0,
+        }
+    }
+
+    /// Attaches the lottery draw that selected the recipient.
+    pub fn with_lottery_draw(mut self, draw: LotteryDraw) -> Self {
+        self.selection = RecipientSelection::Lottery(draw);
+        self
+    }
+
+    /// Returns the lottery draw that selected the recipient, if any.
+    pub fn lottery_draw(&self) -> Option<LotteryDraw> {
+        match &self.selection {
+            RecipientSelection::Lottery(draw) => Some(draw.clone()),
+            RecipientSelection::Scheduled => None,
         }
     }
 
+    /// Attaches the final snapshot of the cycle's total and contributor count.
+    pub fn with_cycle_tally(mut self, cycle_total: i128, contributor_count: u32) -> Self {
+        self.cycle_total = cycle_total;
+        self.contributor_count = contributor_count;
+        self
+    }
+
     /// Validates that the payout record is sound.
     /// Returns true if all constraints are met.
     pub fn validate(&self) -> bool {
@@ -100,12 +167,103 @@ impl PayoutRecord {
     }
 }
 
+/// A payout credited to its recipient and waiting to be claimed.
+/// 
+/// Payout execution never transfers tokens to the recipient directly, so a
+/// recipient account that cannot receive the token never blocks the cycle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimablePayout {
+    /// Address allowed to claim the payout.
+    pub recipient: Address,
+
+    /// Amount held for the recipient, in stroops.
+    pub amount: i128,
+
+    /// Timestamp when the payout was credited.
+    pub credited_at: u64,
+}
+
+impl ClaimablePayout {
+    /// Returns true once the claim window has passed and the payout may be escheated.
+    pub fn is_expired(&self, now: u64) -> bool {
+        now > self.credited_at.saturating_add(CLAIM_WINDOW)
+    }
+}
+
+/// A payout released to its recipient in installments instead of a lump sum.
+/// 
+/// The first installment is claimable as soon as the payout is credited and
+/// one more unlocks every `interval` seconds after that.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    /// Address allowed to claim the payout.
+    pub recipient: Address,
+
+    /// Full payout amount, in stroops.
+    pub total: i128,
+
+    /// Amount already claimed, in stroops.
+    pub claimed: i128,
+
+    /// Number of installments the payout is split into.
+    pub installments: u32,
+
+    /// Seconds between installments; the group's cycle duration.
+    pub interval: u64,
+
+    /// Timestamp when the payout was credited.
+    pub start: u64,
+}
+
+impl VestingSchedule {
+    /// Returns the part of the payout unlocked at `now`, claimed or not.
+    pub fn vested(&self, now: u64) -> i128 {
+        if self.installments == 0 || self.interval == 0 {
+            return self.total;
+        }
+        let unlocked = (now.saturating_sub(self.start) / self.interval).saturating_add(1);
+        if unlocked >= self.installments as u64 {
+            return self.total;
+        }
+        self.total * unlocked as i128 / self.installments as i128
+    }
+
+    /// Returns the unlocked amount not claimed yet.
+    pub fn claimable(&self, now: u64) -> i128 {
+        self.vested(now) - self.claimed
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use soroban_sdk::{testutils::Address as _, Address, Env};
 
+    #[test]
+    fn test_vesting_schedule_unlocks_one_installment_per_interval() {
+        let env = Env::default();
+        let mut schedule = VestingSchedule {
+            recipient: Address::generate(&env),
+            total: 1_000,
+            claimed: 0,
+            installments: 3,
+            interval: 100,
+            start: 50,
+        };
+
+        assert_eq!(schedule.vested(50), 333);
+        assert_eq!(schedule.vested(149), 333);
+        assert_eq!(schedule.vested(150), 666);
+        assert_eq!(schedule.vested(250), 1_000);
+
+        schedule.claimed = 333;
+        assert_eq!(schedule.claimable(150), 333);
+        assert_eq!(schedule.claimable(10_000), 667);
+    }
+
     #[test]
     fn test_payout_record_creation() {
         let env = Env::default();
@@ -124,6 +282,23 @@ mod tests {
         assert_eq!(payout.cycle_number, 0);
         assert_eq!(payout.amount, 50_000_000);
         assert_eq!(payout.timestamp, 1234567890);
+        assert_eq!(payout.selection, RecipientSelection::Scheduled);
+        assert_eq!(payout.lottery_draw(), None);
+    }
+
+    #[test]
+    fn test_with_lottery_draw() {
+        let env = Env::default();
+        let draw = LotteryDraw {
+            candidate_count: 4,
+            drawn_index: 2,
+            ledger_sequence: 100,
+        };
+
+        let payout = PayoutRecord::new(Address::generate(&env), 1, 0, 50_000_000, 1234567890)
+            .with_lottery_draw(draw.clone());
+
+        assert_eq!(payout.lottery_draw(), Some(draw));
     }
 
     #[test]
@@ -274,4 +449,17 @@ mod tests {
         assert_eq!(payout_cycle_0.recipient, payout_cycle_1.recipient);
         assert_eq!(payout_cycle_0.cycle_number + 1, payout_cycle_1.cycle_number);
     }
+
+    #[test]
+    fn test_claimable_payout_expiry() {
+        let env = Env::default();
+        let claimable = ClaimablePayout {
+            recipient: Address::generate(&env),
+            amount: 50_000_000,
+            credited_at: 1_000,
+        };
+
+        assert!(!claimable.is_expired(1_000 + CLAIM_WINDOW));
+        assert!(claimable.is_expired(1_000 + CLAIM_WINDOW + 1));
+    }
 }
@@ -1,5 +1,6 @@
 use soroban_sdk::{contracttype, Env};
 use crate::error::StellarSaveError;
+use crate::payout::PayoutRecord;
 use crate::storage::StorageKeyBuilder;
 
 /// Pool calculation and management for rotational savings groups.
@@ -121,8 +122,8 @@ impl PoolCalculator {
     /// * `group_id` - ID of the group
     /// 
     /// # Returns
-    /// * `Ok(member_count)` - The number of members in the group
-    /// * `Err(StellarSaveError)` - If group not found or storage error
+    /// * `Ok(member_count)` - The number of members in the group; 0 before
+    ///   anyone has joined
     pub fn get_member_count(env: &Env, group_id: u64) -> Result<u32, StellarSaveError> {
         let members_key = StorageKeyBuilder::group_members(group_id);
         
@@ -130,7 +131,7 @@ impl PoolCalculator {
             .storage()
             .persistent()
             .get(&members_key)
-            .ok_or(StellarSaveError::GroupNotFound)?;
+            .unwrap_or(soroban_sdk::Vec::new(env));
         
         Ok(members.len() as u32)
     }
@@ -165,6 +166,9 @@ impl PoolCalculator {
     /// 
     /// # Returns
     /// * `Ok(total)` - The total contributions for the cycle (0 if not set)
+    /// 
+    /// The running total lives in temporary storage until the cycle pays out;
+    /// after that the snapshot in the cycle's `PayoutRecord` is returned.
     pub fn get_cycle_contributions_total(
         env: &Env,
         group_id: u64,
@@ -172,13 +176,13 @@ impl PoolCalculator {
     ) -> Result<i128, StellarSaveError> {
         let total_key = StorageKeyBuilder::contribution_cycle_total(group_id, cycle);
         
-        let total: i128 = env
-            .storage()
-            .persistent()
-            .get(&total_key)
-            .unwrap_or(0);
+        if let Some(total) = env.storage().temporary().get::<_, i128>(&total_key) {
+            return Ok(total);
+        }
         
-        Ok(total)
+        Ok(Self::get_payout_snapshot(env, group_id, cycle)
+            .map(|record| record.cycle_total)
+            .unwrap_or(0))
     }
     
     /// Retrieves the number of contributors for the current cycle from storage.
@@ -190,6 +194,9 @@ impl PoolCalculator {
     /// 
     /// # Returns
     /// * `Ok(count)` - The number of members who have contributed (0 if not set)
+    /// 
+    /// Falls back to the cycle's `PayoutRecord` snapshot once the temporary
+    /// count is gone, like `get_cycle_contributions_total`.
     pub fn get_cycle_contributor_count(
         env: &Env,
         group_id: u64,
@@ -197,20 +204,84 @@ impl PoolCalculator {
     ) -> Result<u32, StellarSaveError> {
         let count_key = StorageKeyBuilder::contribution_cycle_count(group_id, cycle);
         
+        if let Some(count) = env.storage().temporary().get::<_, u32>(&count_key) {
+            return Ok(count);
+        }
+        
+        Ok(Self::get_payout_snapshot(env, group_id, cycle)
+            .map(|record| record.contributor_count)
+            .unwrap_or(0))
+    }
+    
+    /// Reads the `PayoutRecord` of a paid-out cycle, which holds the final
+    /// snapshot of the cycle's total and contributor count.
+    fn get_payout_snapshot(env: &Env, group_id: u64, cycle: u32) -> Option<PayoutRecord> {
+        env.storage()
+            .persistent()
+            .get(&StorageKeyBuilder::payout_record(group_id, cycle))
+    }
+    
+    /// Retrieves the number of offline (attested) contributions for a cycle.
+    /// 
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `group_id` - ID of the group
+    /// * `cycle` - Cycle number
+    /// 
+    /// # Returns
+    /// * `Ok(count)` - The number of attested offline contributions (0 if not set)
+    pub fn get_cycle_offline_count(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+    ) -> Result<u32, StellarSaveError> {
+        let offline_key = StorageKeyBuilder::contribution_offline_count(group_id, cycle);
+        
         let count: u32 = env
             .storage()
             .persistent()
-            .get(&count_key)
+            .get(&offline_key)
             .unwrap_or(0);
         
         Ok(count)
     }
     
+    /// Retrieves the number of obligations deferred for hardship in a cycle.
+    /// 
+    /// # Returns
+    /// * `Ok(count)` - The number of deferred obligations (0 if not set)
+    pub fn get_cycle_deferred_count(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+    ) -> Result<u32, StellarSaveError> {
+        let deferred_key = StorageKeyBuilder::contribution_deferred_count(group_id, cycle);
+        Ok(env.storage().persistent().get(&deferred_key).unwrap_or(0))
+    }
+    
+    /// Retrieves the uncovered shortfall left by defaulted members in a cycle.
+    /// 
+    /// # Returns
+    /// * `Ok(shortfall)` - The uncovered amount (0 if none)
+    pub fn get_cycle_shortfall(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+    ) -> Result<i128, StellarSaveError> {
+        let shortfall_key = StorageKeyBuilder::contribution_shortfall(group_id, cycle);
+        Ok(env.storage().persistent().get(&shortfall_key).unwrap_or(0))
+    }
+    
     /// Builds complete pool information for a group and cycle.
     /// 
     /// This is the primary function for getting comprehensive pool data.
     /// It aggregates member count, contribution amount, and current cycle status.
     /// 
+    /// Contributions attested offline count toward cycle completion but carry
+    /// no tokens, so `total_pool_amount` only covers the on-chain contributors.
+    /// Obligations deferred for hardship are excluded the same way, and any
+    /// shortfall left by defaulted members is deducted.
+    /// 
     /// # Arguments
     /// * `env` - Soroban environment
     /// * `group_id` - ID of the group
@@ -230,8 +301,21 @@ impl PoolCalculator {
         // Get contribution amount
         let contribution_amount = Self::get_contribution_amount(env, group_id)?;
         
-        // Calculate total pool
-        let total_pool_amount = Self::calculate_total_pool(contribution_amount, member_count)?;
+        // Calculate total pool, excluding members who paid offline or deferred this cycle
+        let offline_count = Self::get_cycle_offline_count(env, group_id, cycle)?;
+        let deferred_count = Self::get_cycle_deferred_count(env, group_id, cycle)?;
+        let on_chain_members = member_count
+            .saturating_sub(offline_count)
+            .saturating_sub(deferred_count);
+        let expected_pool = if on_chain_members == 0 {
+            0
+        } else {
+            Self::calculate_total_pool(contribution_amount, on_chain_members)?
+        };
+        let shortfall = Self::get_cycle_shortfall(env, group_id, cycle)?;
+        let total_pool_amount = expected_pool
+            .checked_sub(shortfall)
+            .ok_or(StellarSaveError::Overflow)?;
         
         // Get current cycle contributions
         let current_contributions = Self::get_cycle_contributions_total(env, group_id, cycle)?;
@@ -240,7 +324,7 @@ impl PoolCalculator {
         let contributors_count = Self::get_cycle_contributor_count(env, group_id, cycle)?;
         
         // Determine if cycle is complete
-        let is_cycle_complete = contributors_count >= member_count;
+        let is_cycle_complete = member_count > 0 && contributors_count >= member_count;
         
         Ok(PoolInfo {
             group_id,
@@ -254,6 +338,68 @@ impl PoolCalculator {
         })
     }
     
+    /// Retrieves the late fees collected for a cycle.
+    /// 
+    /// Late fees are kept apart from the regular contributions so pool
+    /// completeness checks are unaffected, and are added to the payout.
+    /// 
+    /// # Returns
+    /// * `Ok(total)` - The late fees for the cycle (0 if none)
+    pub fn get_cycle_late_fees(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+    ) -> Result<i128, StellarSaveError> {
+        let fees_key = StorageKeyBuilder::contribution_late_fees(group_id, cycle);
+        Ok(env.storage().persistent().get(&fees_key).unwrap_or(0))
+    }
+
+    /// Calculates the late fee for a contribution.
+    /// 
+    /// # Arguments
+    /// * `contribution_amount` - The regular contribution amount
+    /// * `late_fee_bps` - Fee rate in basis points
+    /// 
+    /// # Returns
+    /// * `Ok(fee)` - The fee, rounded down
+    /// * `Err(StellarSaveError::Overflow)` - If the calculation overflows
+    pub fn calculate_late_fee(
+        contribution_amount: i128,
+        late_fee_bps: u32,
+    ) -> Result<i128, StellarSaveError> {
+        let fee = contribution_amount
+            .checked_mul(late_fee_bps as i128)
+            .ok_or(StellarSaveError::Overflow)?
+            / 10_000;
+        Ok(fee)
+    }
+    
+    /// Calculates the insurance premium charged on a contribution.
+    /// 
+    /// # Arguments
+    /// * `contribution_amount` - The regular contribution amount
+    /// * `insurance_bps` - Premium rate in basis points
+    /// 
+    /// # Returns
+    /// * `Ok(premium)` - The premium, rounded down
+    /// * `Err(StellarSaveError::Overflow)` - If the calculation overflows
+    pub fn calculate_insurance_premium(
+        contribution_amount: i128,
+        insurance_bps: u32,
+    ) -> Result<i128, StellarSaveError> {
+        let premium = contribution_amount
+            .checked_mul(insurance_bps as i128)
+            .ok_or(StellarSaveError::Overflow)?
+            / 10_000;
+        Ok(premium)
+    }
+    
+    /// Retrieves a group's insurance fund balance.
+    pub fn get_insurance_balance(env: &Env, group_id: u64) -> i128 {
+        let insurance_key = StorageKeyBuilder::pool_insurance(group_id);
+        env.storage().persistent().get(&insurance_key).unwrap_or(0)
+    }
+    
     /// Validates that a pool is ready for payout.
     /// 
     /// A pool is ready when:
@@ -306,6 +452,43 @@ impl PoolCalculator {
 
         Ok(net_payout)
     }
+
+    /// Calculates the bonus owed to a recipient whose payout settled late.
+    /// 
+    /// The bonus accrues linearly at `bonus_bps` of the payout for every full
+    /// `cycle_duration` of delay past the cycle deadline, and is capped by the
+    /// funds available in the late-fee pool.
+    /// 
+    /// # Arguments
+    /// * `payout_amount` - The regular payout for the cycle
+    /// * `bonus_bps` - Bonus rate in basis points per cycle of delay
+    /// * `delay` - Seconds elapsed since the cycle deadline
+    /// * `cycle_duration` - Length of a cycle in seconds
+    /// * `available` - Current late-fee pool balance
+    /// 
+    /// # Returns
+    /// * `Ok(bonus)` - The bonus to pay (0 when not late or no rate is set)
+    /// * `Err(StellarSaveError::Overflow)` - If the calculation overflows
+    pub fn calculate_late_payout_bonus(
+        payout_amount: i128,
+        bonus_bps: u32,
+        delay: u64,
+        cycle_duration: u64,
+        available: i128,
+    ) -> Result<i128, StellarSaveError> {
+        if bonus_bps == 0 || delay == 0 || cycle_duration == 0 || available <= 0 {
+            return Ok(0);
+        }
+
+        let late_cycles = (delay / cycle_duration) as i128;
+        let bonus = payout_amount
+            .checked_mul(bonus_bps as i128)
+            .and_then(|v| v.checked_mul(late_cycles))
+            .ok_or(StellarSaveError::Overflow)?
+            / 10_000;
+
+        Ok(bonus.min(available))
+    }
 }
 
 #[cfg(test)]
@@ -700,4 +883,31 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), StellarSaveError::InvalidAmount);
     }
+
+    #[test]
+    fn test_calculate_insurance_premium() {
+        assert_eq!(PoolCalculator::calculate_insurance_premium(10_000, 150).unwrap(), 150);
+        assert_eq!(PoolCalculator::calculate_insurance_premium(99, 100).unwrap(), 0);
+        assert_eq!(PoolCalculator::calculate_insurance_premium(10_000, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_calculate_late_payout_bonus() {
+        // 2 full cycles late at 100 bps per cycle: 2% of 1000
+        let bonus = PoolCalculator::calculate_late_payout_bonus(1_000, 100, 7_300, 3_600, 500);
+        assert_eq!(bonus.unwrap(), 20);
+        
+        // Less than a full cycle late earns nothing
+        let bonus = PoolCalculator::calculate_late_payout_bonus(1_000, 100, 3_599, 3_600, 500);
+        assert_eq!(bonus.unwrap(), 0);
+    }
+
+    #[test]
+    fn test_calculate_late_payout_bonus_capped_by_pool() {
+        let bonus = PoolCalculator::calculate_late_payout_bonus(1_000, 1_000, 36_000, 3_600, 50);
+        assert_eq!(bonus.unwrap(), 50);
+        
+        let bonus = PoolCalculator::calculate_late_payout_bonus(1_000, 1_000, 36_000, 3_600, 0);
+        assert_eq!(bonus.unwrap(), 0);
+    }
 }
@@ -0,0 +1,74 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, Symbol};
+
+/// Decimals of USD amounts stored by the contract, matching stroops.
+pub const USD_DECIMALS: u32 = 7;
+
+/// Oldest price, in seconds, a pegged contribution amount may be computed from.
+pub const MAX_PRICE_AGE: u64 = 30 * 60;
+
+/// Asset identifier of SEP-40 price feeds such as Reflector.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Asset {
+    /// A Stellar asset, by token contract address.
+    Stellar(Address),
+
+    /// Any other asset, by ticker.
+    Other(Symbol),
+}
+
+/// A price quoted by the oracle in its base asset (USD).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    /// Price with the oracle's `decimals()`.
+    pub price: i128,
+
+    /// Timestamp the price was recorded at.
+    pub timestamp: u64,
+}
+
+/// Cross-contract interface of a SEP-40 price oracle.
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    /// Number of decimals prices are quoted with.
+    fn decimals(env: Env) -> u32;
+
+    /// Most recent price of `asset`, if the oracle tracks it.
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+}
+
+/// Converts a USD amount with `USD_DECIMALS` decimals into token base units
+/// at `price`, rounding up so the contribution never falls short of the
+/// target. Returns `None` on overflow or a non-positive price.
+pub fn usd_to_token_amount(
+    usd_amount: i128,
+    price: i128,
+    price_decimals: u32,
+    token_decimals: u32,
+) -> Option<i128> {
+    if price <= 0 {
+        return None;
+    }
+    let numerator = usd_amount
+        .checked_mul(10i128.checked_pow(price_decimals)?)?
+        .checked_mul(10i128.checked_pow(token_decimals)?)?;
+    let denominator = price.checked_mul(10i128.checked_pow(USD_DECIMALS)?)?;
+    Some((numerator + denominator - 1) / denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usd_to_token_amount() {
+        // $10 at $0.125 per token, 14-decimal prices, 7-decimal token
+        let amount = usd_to_token_amount(100_000_000, 12_500_000_000_000, 14, 7);
+        assert_eq!(amount, Some(800_000_000));
+
+        // Rounds up to the next base unit
+        assert_eq!(usd_to_token_amount(1, 3, 0, 7), Some(1));
+        assert_eq!(usd_to_token_amount(10_000_000, 0, 14, 7), None);
+    }
+}
@@ -0,0 +1,158 @@
+use soroban_sdk::{contracttype, Address, Env};
+use crate::storage::StorageKeyBuilder;
+
+/// Score points awarded for each group a member saw through to completion.
+pub const COMPLETION_POINTS: u32 = 10;
+
+/// Score points deducted for each cycle a member defaulted on.
+pub const DEFAULT_PENALTY: u32 = 25;
+
+/// Score points deducted for each group the member was replaced in after
+/// defaulting.
+pub const REPLACEMENT_PENALTY: u32 = 50;
+
+/// Cross-group track record of an address.
+///
+/// Reputation is global: it accumulates over every group the address has
+/// been a member of, so creators can gate joining on past behaviour.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Reputation {
+    /// Contributions made before the cycle deadline.
+    pub on_time_contributions: u32,
+
+    /// Contributions made during a grace period.
+    pub late_contributions: u32,
+
+    /// Consecutive on-time contributions since the last late payment or default.
+    pub current_streak: u32,
+
+    /// Longest on-time streak ever reached.
+    pub longest_streak: u32,
+
+    /// Cycles the address defaulted on.
+    pub defaults: u32,
+
+    /// Groups the address was a member of when they completed.
+    pub groups_completed: u32,
+
+    /// Groups the address was replaced in after defaulting.
+    pub times_replaced: u32,
+}
+
+impl Reputation {
+    /// Records an on-time contribution and extends the streak.
+    pub fn record_on_time(&mut self) {
+        self.on_time_contributions = self.on_time_contributions.saturating_add(1);
+        self.current_streak = self.current_streak.saturating_add(1);
+        self.longest_streak = self.longest_streak.max(self.current_streak);
+    }
+
+    /// Records a late contribution, which breaks the streak.
+    pub fn record_late(&mut self) {
+        self.late_contributions = self.late_contributions.saturating_add(1);
+        self.current_streak = 0;
+    }
+
+    /// Records a missed contribution, which breaks the streak.
+    pub fn record_default(&mut self) {
+        self.defaults = self.defaults.saturating_add(1);
+        self.current_streak = 0;
+    }
+
+    /// Records being replaced in a group after defaulting.
+    pub fn record_replaced(&mut self) {
+        self.times_replaced = self.times_replaced.saturating_add(1);
+        self.current_streak = 0;
+    }
+
+    /// Records membership of a group that ran to completion.
+    pub fn record_group_completed(&mut self) {
+        self.groups_completed = self.groups_completed.saturating_add(1);
+    }
+
+    /// Credit score derived from the record.
+    ///
+    /// One point per on-time contribution and per step of the longest streak,
+    /// `COMPLETION_POINTS` per completed group, minus `DEFAULT_PENALTY` per
+    /// default and `REPLACEMENT_PENALTY` per replacement. Never negative.
+    pub fn score(&self) -> u32 {
+        let earned = self
+            .on_time_contributions
+            .saturating_add(self.longest_streak)
+            .saturating_add(self.groups_completed.saturating_mul(COMPLETION_POINTS));
+        let penalty = self
+            .defaults
+            .saturating_mul(DEFAULT_PENALTY)
+            .saturating_add(self.times_replaced.saturating_mul(REPLACEMENT_PENALTY));
+        earned.saturating_sub(penalty)
+    }
+}
+
+/// Reads and updates reputation records in storage.
+pub struct ReputationTracker;
+
+impl ReputationTracker {
+    /// Returns an address's reputation, or an empty record if it has none.
+    pub fn get(env: &Env, address: &Address) -> Reputation {
+        env.storage()
+            .persistent()
+            .get(&StorageKeyBuilder::member_reputation(address.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Applies `update` to an address's reputation and stores the result.
+    pub fn update<F: FnOnce(&mut Reputation)>(env: &Env, address: &Address, update: F) {
+        let mut reputation = Self::get(env, address);
+        update(&mut reputation);
+        env.storage()
+            .persistent()
+            .set(&StorageKeyBuilder::member_reputation(address.clone()), &reputation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streaks() {
+        let mut reputation = Reputation::default();
+        reputation.record_on_time();
+        reputation.record_on_time();
+        reputation.record_late();
+        reputation.record_on_time();
+
+        assert_eq!(reputation.on_time_contributions, 3);
+        assert_eq!(reputation.late_contributions, 1);
+        assert_eq!(reputation.current_streak, 1);
+        assert_eq!(reputation.longest_streak, 2);
+    }
+
+    #[test]
+    fn test_score() {
+        let mut reputation = Reputation::default();
+        reputation.record_on_time();
+        reputation.record_on_time();
+        reputation.record_group_completed();
+        // 2 on-time + streak 2 + 10 for the completed group
+        assert_eq!(reputation.score(), 14);
+
+        reputation.record_default();
+        assert_eq!(reputation.score(), 0);
+    }
+
+    #[test]
+    fn test_replacement_penalty() {
+        let mut reputation = Reputation::default();
+        for _ in 0..30 {
+            reputation.record_on_time();
+        }
+        assert_eq!(reputation.score(), 60);
+
+        reputation.record_replaced();
+        assert_eq!(reputation.times_replaced, 1);
+        assert_eq!(reputation.current_streak, 0);
+        assert_eq!(reputation.score(), 10);
+    }
+}
@@ -1,66 +1,48 @@
-﻿use soroban_sdk::{contracterror, contracttype};
+﻿use crate::error::StellarSaveError;
+pub use crate::group::GroupStatus;
 
 /// Error types for invalid state transitions.
-#[contracterror]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
-#[repr(u32)]
+///
+/// Carries the statuses involved so internal callers and tests can tell
+/// which transition was refused; converts into `StellarSaveError::InvalidState`
+/// at the contract boundary.
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum StatusError {
-    /// Attempted an invalid state transition.
-    InvalidTransition = 1,
+    /// Attempted a transition the state machine does not allow, including
+    /// a "transition" to the status the group already has.
+    InvalidTransition { from: GroupStatus, to: GroupStatus },
     /// Cannot transition from Completed state.
-    AlreadyCompleted = 2,
+    AlreadyCompleted,
     /// Cannot transition from Cancelled state.
-    AlreadyCancelled = 3,
+    AlreadyCancelled,
 }
 
+impl From<StatusError> for StellarSaveError {
+    fn from(_: StatusError) -> Self {
+        StellarSaveError::InvalidState
+    }
+}
 
-/// GroupStatus enum representing the lifecycle states of a savings group.
-/// 
-/// A group progresses through various states from creation to completion.
-/// State transitions are validated to ensure proper group lifecycle management.
+/// Transition rules and helpers for `GroupStatus`.
 /// 
 /// # State Flow
 /// ```text
 /// Pending -> Active -> Completed
 ///     |         |
-///     v         v
-/// Cancelled   Paused -> Active
-///                |
-///                v
-///            Cancelled
+///     v         +--> Paused -> Active
+/// Cancelled     |       |
+///               |       v
+///               |   Cancelled
+///               v
+///          GracePeriod -> Active
+///               |
+///               v
+///           Defaulted -> Cancelled
 /// ```
-#[contracttype]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
-#[repr(u32)]
-pub enum GroupStatus {
-    /// Group has been created but not yet started.
-    /// Waiting for minimum members to join before activation.
-    /// Can transition to: Active, Cancelled
-    Pending = 0,
-    
-    /// Group is actively running with members contributing and receiving payouts.
-    /// Normal operational state where contributions and payouts occur.
-    /// Can transition to: Paused, Completed, Cancelled
-    Active = 1,
-    
-    /// Group is temporarily paused.
-    /// No contributions or payouts can occur while paused.
-    /// Can transition to: Active, Cancelled
-    Paused = 2,
-    
-    /// Group has completed all cycles successfully.
-    /// All members have received their payouts.
-    /// Terminal state - no further transitions allowed.
-    Completed = 3,
-    
-    /// Group has been cancelled and will not continue.
-    /// May occur due to insufficient members, admin action, or other issues.
-    /// Terminal state - no further transitions allowed.
-    Cancelled = 4,
-}
-
+/// Pending, Active, Paused and GracePeriod groups can all be cancelled.
 impl GroupStatus {
-    /// Checks if a transition from the current status to a new status is valid.
+    /// Checks that moving from the current status to a new status is a
+    /// real, allowed change.
     /// 
     /// # Arguments
     /// * `new_status` - The desired new status
@@ -71,31 +53,23 @@ impl GroupStatus {
     /// 
     /// # Valid Transitions
     /// - Pending -> Active, Cancelled
-    /// - Active -> Paused, Completed, Cancelled
+    /// - Active -> Paused, Completed, Cancelled, GracePeriod
     /// - Paused -> Active, Cancelled
+    /// - GracePeriod -> Active, Defaulted, Cancelled
+    /// - Defaulted -> Cancelled
     /// - Completed -> None (terminal state)
     /// - Cancelled -> None (terminal state)
-    pub fn can_transition_to(&self, new_status: GroupStatus) -> Result<(), StatusError> {
-        match (self, new_status) {
-            // From Pending
-            (GroupStatus::Pending, GroupStatus::Active) => Ok(()),
-            (GroupStatus::Pending, GroupStatus::Cancelled) => Ok(()),
-            
-            // From Active
-            (GroupStatus::Active, GroupStatus::Paused) => Ok(()),
-            (GroupStatus::Active, GroupStatus::Completed) => Ok(()),
-            (GroupStatus::Active, GroupStatus::Cancelled) => Ok(()),
-            
-            // From Paused
-            (GroupStatus::Paused, GroupStatus::Active) => Ok(()),
-            (GroupStatus::Paused, GroupStatus::Cancelled) => Ok(()),
-            
-            // Terminal states cannot transition
-            (GroupStatus::Completed, _) => Err(StatusError::AlreadyCompleted),
-            (GroupStatus::Cancelled, _) => Err(StatusError::AlreadyCancelled),
-            
-            // All other transitions are invalid
-            _ => Err(StatusError::InvalidTransition),
+    pub fn validate_transition(&self, new_status: &GroupStatus) -> Result<(), StatusError> {
+        match self {
+            GroupStatus::Completed => Err(StatusError::AlreadyCompleted),
+            GroupStatus::Cancelled => Err(StatusError::AlreadyCancelled),
+            from if from == new_status || !from.can_transition_to(new_status) => {
+                Err(StatusError::InvalidTransition {
+                    from: from.clone(),
+                    to: new_status.clone(),
+                })
+            }
+            _ => Ok(()),
         }
     }
     
@@ -108,25 +82,10 @@ impl GroupStatus {
     /// * `Ok(new_status)` if the transition is valid
     /// * `Err(StatusError)` if the transition is invalid
     pub fn transition_to(&self, new_status: GroupStatus) -> Result<GroupStatus, StatusError> {
-        self.can_transition_to(new_status)?;
+        self.validate_transition(&new_status)?;
         Ok(new_status)
     }
     
-    /// Checks if the group is in a terminal state (Completed or Cancelled).
-    pub fn is_terminal(&self) -> bool {
-        matches!(self, GroupStatus::Completed | GroupStatus::Cancelled)
-    }
-    
-    /// Checks if the group can accept contributions in its current state.
-    pub fn can_accept_contributions(&self) -> bool {
-        matches!(self, GroupStatus::Active)
-    }
-    
-    /// Checks if the group can process payouts in its current state.
-    pub fn can_process_payouts(&self) -> bool {
-        matches!(self, GroupStatus::Active)
-    }
-    
     /// Checks if new members can join the group in its current state.
     pub fn can_accept_members(&self) -> bool {
         matches!(self, GroupStatus::Pending | GroupStatus::Active)
@@ -140,6 +99,8 @@ impl GroupStatus {
             GroupStatus::Paused => "Paused",
             GroupStatus::Completed => "Completed",
             GroupStatus::Cancelled => "Cancelled",
+            GroupStatus::GracePeriod => "GracePeriod",
+            GroupStatus::Defaulted => "Defaulted",
         }
     }
     
@@ -158,18 +119,17 @@ impl GroupStatus {
             2 => Some(GroupStatus::Paused),
             3 => Some(GroupStatus::Completed),
             4 => Some(GroupStatus::Cancelled),
+            5 => Some(GroupStatus::GracePeriod),
+            6 => Some(GroupStatus::Defaulted),
             _ => None,
         }
     }
     
     /// Converts the GroupStatus to its u32 representation.
     pub fn to_u32(&self) -> u32 {
-        *self as u32
+        self.clone() as u32
     }
-}
 
-// Implement Display-like functionality through as_str
-impl GroupStatus {
     /// Returns a detailed description of the status.
     pub fn description(&self) -> &'static str {
         match self {
@@ -178,6 +138,8 @@ impl GroupStatus {
             GroupStatus::Paused => "Group is temporarily paused, no contributions or payouts allowed",
             GroupStatus::Completed => "Group has completed all cycles successfully",
             GroupStatus::Cancelled => "Group has been cancelled and will not continue",
+            GroupStatus::GracePeriod => "Group's cycle deadline has passed, waiting for late contributions",
+            GroupStatus::Defaulted => "Group has defaulted on contributions it could not cover",
         }
     }
 }
@@ -191,33 +153,33 @@ mod tests {
     fn test_valid_transitions_from_pending() {
         let status = GroupStatus::Pending;
         
-        assert!(status.can_transition_to(GroupStatus::Active).is_ok());
-        assert!(status.can_transition_to(GroupStatus::Cancelled).is_ok());
+        assert!(status.validate_transition(&GroupStatus::Active).is_ok());
+        assert!(status.validate_transition(&GroupStatus::Cancelled).is_ok());
         
-        assert!(status.can_transition_to(GroupStatus::Paused).is_err());
-        assert!(status.can_transition_to(GroupStatus::Completed).is_err());
+        assert!(status.validate_transition(&GroupStatus::Paused).is_err());
+        assert!(status.validate_transition(&GroupStatus::Completed).is_err());
     }
 
     #[test]
     fn test_valid_transitions_from_active() {
         let status = GroupStatus::Active;
         
-        assert!(status.can_transition_to(GroupStatus::Paused).is_ok());
-        assert!(status.can_transition_to(GroupStatus::Completed).is_ok());
-        assert!(status.can_transition_to(GroupStatus::Cancelled).is_ok());
+        assert!(status.validate_transition(&GroupStatus::Paused).is_ok());
+        assert!(status.validate_transition(&GroupStatus::Completed).is_ok());
+        assert!(status.validate_transition(&GroupStatus::Cancelled).is_ok());
         
-        assert!(status.can_transition_to(GroupStatus::Pending).is_err());
+        assert!(status.validate_transition(&GroupStatus::Pending).is_err());
     }
 
     #[test]
     fn test_valid_transitions_from_paused() {
         let status = GroupStatus::Paused;
         
-        assert!(status.can_transition_to(GroupStatus::Active).is_ok());
-        assert!(status.can_transition_to(GroupStatus::Cancelled).is_ok());
+        assert!(status.validate_transition(&GroupStatus::Active).is_ok());
+        assert!(status.validate_transition(&GroupStatus::Cancelled).is_ok());
         
-        assert!(status.can_transition_to(GroupStatus::Pending).is_err());
-        assert!(status.can_transition_to(GroupStatus::Completed).is_err());
+        assert!(status.validate_transition(&GroupStatus::Pending).is_err());
+        assert!(status.validate_transition(&GroupStatus::Completed).is_err());
     }
 
     #[test]
@@ -225,15 +187,15 @@ mod tests {
         let status = GroupStatus::Completed;
         
         assert_eq!(
-            status.can_transition_to(GroupStatus::Active),
+            status.validate_transition(&GroupStatus::Active),
             Err(StatusError::AlreadyCompleted)
         );
         assert_eq!(
-            status.can_transition_to(GroupStatus::Paused),
+            status.validate_transition(&GroupStatus::Paused),
             Err(StatusError::AlreadyCompleted)
         );
         assert_eq!(
-            status.can_transition_to(GroupStatus::Cancelled),
+            status.validate_transition(&GroupStatus::Cancelled),
             Err(StatusError::AlreadyCompleted)
         );
         
@@ -245,21 +207,32 @@ mod tests {
         let status = GroupStatus::Cancelled;
         
         assert_eq!(
-            status.can_transition_to(GroupStatus::Active),
+            status.validate_transition(&GroupStatus::Active),
             Err(StatusError::AlreadyCancelled)
         );
         assert_eq!(
-            status.can_transition_to(GroupStatus::Paused),
+            status.validate_transition(&GroupStatus::Paused),
             Err(StatusError::AlreadyCancelled)
         );
         assert_eq!(
-            status.can_transition_to(GroupStatus::Completed),
+            status.validate_transition(&GroupStatus::Completed),
             Err(StatusError::AlreadyCancelled)
         );
         
         assert!(status.is_terminal());
     }
 
+    #[test]
+    fn test_same_status_is_not_a_transition() {
+        assert_eq!(
+            GroupStatus::Active.validate_transition(&GroupStatus::Active),
+            Err(StatusError::InvalidTransition {
+                from: GroupStatus::Active,
+                to: GroupStatus::Active,
+            })
+        );
+    }
+
     #[test]
     fn test_transition_to_success() {
         let status = GroupStatus::Pending;
@@ -274,8 +247,13 @@ mod tests {
         let status = GroupStatus::Pending;
         let result = status.transition_to(GroupStatus::Paused);
         
-        assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), StatusError::InvalidTransition);
+        assert_eq!(
+            result.unwrap_err(),
+            StatusError::InvalidTransition {
+                from: GroupStatus::Pending,
+                to: GroupStatus::Paused,
+            }
+        );
     }
 
     #[test]
@@ -288,12 +266,12 @@ mod tests {
     }
 
     #[test]
-    fn test_can_accept_contributions() {
-        assert!(!GroupStatus::Pending.can_accept_contributions());
-        assert!(GroupStatus::Active.can_accept_contributions());
-        assert!(!GroupStatus::Paused.can_accept_contributions());
-        assert!(!GroupStatus::Completed.can_accept_contributions());
-        assert!(!GroupStatus::Cancelled.can_accept_contributions());
+    fn test_accepts_contributions() {
+        assert!(!GroupStatus::Pending.accepts_contributions());
+        assert!(GroupStatus::Active.accepts_contributions());
+        assert!(!GroupStatus::Paused.accepts_contributions());
+        assert!(!GroupStatus::Completed.accepts_contributions());
+        assert!(!GroupStatus::Cancelled.accepts_contributions());
     }
 
     #[test]
@@ -321,6 +299,8 @@ mod tests {
         assert_eq!(GroupStatus::Paused.as_str(), "Paused");
         assert_eq!(GroupStatus::Completed.as_str(), "Completed");
         assert_eq!(GroupStatus::Cancelled.as_str(), "Cancelled");
+        assert_eq!(GroupStatus::GracePeriod.as_str(), "GracePeriod");
+        assert_eq!(GroupStatus::Defaulted.as_str(), "Defaulted");
     }
 
     #[test]
@@ -330,6 +310,8 @@ mod tests {
         assert!(GroupStatus::Paused.description().contains("paused"));
         assert!(GroupStatus::Completed.description().contains("completed"));
         assert!(GroupStatus::Cancelled.description().contains("cancelled"));
+        assert!(GroupStatus::GracePeriod.description().contains("late"));
+        assert!(GroupStatus::Defaulted.description().contains("defaulted"));
     }
 
     #[test]
@@ -339,7 +321,9 @@ mod tests {
         assert_eq!(GroupStatus::from_u32(2), Some(GroupStatus::Paused));
         assert_eq!(GroupStatus::from_u32(3), Some(GroupStatus::Completed));
         assert_eq!(GroupStatus::from_u32(4), Some(GroupStatus::Cancelled));
-        assert_eq!(GroupStatus::from_u32(5), None);
+        assert_eq!(GroupStatus::from_u32(5), Some(GroupStatus::GracePeriod));
+        assert_eq!(GroupStatus::from_u32(6), Some(GroupStatus::Defaulted));
+        assert_eq!(GroupStatus::from_u32(7), None);
         assert_eq!(GroupStatus::from_u32(999), None);
     }
 
@@ -350,11 +334,13 @@ mod tests {
         assert_eq!(GroupStatus::Paused.to_u32(), 2);
         assert_eq!(GroupStatus::Completed.to_u32(), 3);
         assert_eq!(GroupStatus::Cancelled.to_u32(), 4);
+        assert_eq!(GroupStatus::GracePeriod.to_u32(), 5);
+        assert_eq!(GroupStatus::Defaulted.to_u32(), 6);
     }
 
     #[test]
     fn test_round_trip_conversion() {
-        for i in 0..=4 {
+        for i in 0..=6 {
             let status = GroupStatus::from_u32(i).unwrap();
             assert_eq!(status.to_u32(), i);
         }
@@ -381,7 +367,7 @@ mod tests {
         
         status = status.transition_to(GroupStatus::Active).unwrap();
         assert_eq!(status, GroupStatus::Active);
-        assert!(status.can_accept_contributions());
+        assert!(status.accepts_contributions());
         
         status = status.transition_to(GroupStatus::Completed).unwrap();
         assert_eq!(status, GroupStatus::Completed);
@@ -395,15 +381,37 @@ mod tests {
         
         status = status.transition_to(GroupStatus::Active).unwrap();
         status = status.transition_to(GroupStatus::Paused).unwrap();
-        assert!(!status.can_accept_contributions());
+        assert!(!status.accepts_contributions());
         
         status = status.transition_to(GroupStatus::Active).unwrap();
-        assert!(status.can_accept_contributions());
+        assert!(status.accepts_contributions());
         
         status = status.transition_to(GroupStatus::Completed).unwrap();
         assert!(status.is_terminal());
     }
 
+    #[test]
+    fn test_lifecycle_through_grace_period() {
+        // Active -> GracePeriod -> Active once stragglers pay
+        let status = GroupStatus::Active.transition_to(GroupStatus::GracePeriod).unwrap();
+        assert!(status.accepts_contributions());
+        assert!(!status.can_process_payouts());
+        assert_eq!(status.transition_to(GroupStatus::Active), Ok(GroupStatus::Active));
+
+        // GracePeriod -> Defaulted -> Cancelled when they don't
+        let status = status.transition_to(GroupStatus::Defaulted).unwrap();
+        assert!(!status.accepts_contributions());
+        assert!(!status.is_terminal());
+        assert_eq!(
+            status.validate_transition(&GroupStatus::Active),
+            Err(StatusError::InvalidTransition {
+                from: GroupStatus::Defaulted,
+                to: GroupStatus::Active,
+            })
+        );
+        assert!(status.transition_to(GroupStatus::Cancelled).is_ok());
+    }
+
     #[test]
     fn test_cancellation_from_various_states() {
         // Can cancel from Pending
@@ -414,5 +422,9 @@ mod tests {
         
         // Can cancel from Paused
         assert!(GroupStatus::Paused.transition_to(GroupStatus::Cancelled).is_ok());
+        
+        // Can cancel from GracePeriod and Defaulted
+        assert!(GroupStatus::GracePeriod.transition_to(GroupStatus::Cancelled).is_ok());
+        assert!(GroupStatus::Defaulted.transition_to(GroupStatus::Cancelled).is_ok());
     }
 }
@@ -0,0 +1,96 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+use crate::{ContractConfig, Role};
+
+/// Longest delay the timelock may be configured with (30 days).
+pub const MAX_TIMELOCK_DELAY: u64 = 30 * 24 * 60 * 60;
+
+/// A sensitive administrative operation that goes through the timelock.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimelockAction {
+    /// Replace the global configuration, as `update_config` does.
+    UpdateConfig(ContractConfig),
+
+    /// Add a token to the allowlist, as `add_allowed_token` does.
+    AllowToken(Address),
+
+    /// Remove a token from the allowlist, as `remove_allowed_token` does.
+    DisallowToken(Address),
+
+    /// Sweep an expired unclaimed payout into the group's insurance fund,
+    /// as `escheat_payout` does: `(group_id, cycle)`.
+    EscheatPayout(u64, u32),
+
+    /// Change the timelock delay itself, in seconds.
+    SetDelay(u64),
+
+    /// Replace the contract code with the uploaded WASM of the given hash,
+    /// as `upgrade` does.
+    Upgrade(BytesN<32>),
+}
+
+impl TimelockAction {
+    /// Role that may queue, execute and cancel the action; the Owner may
+    /// handle every action.
+    pub fn required_role(&self) -> Role {
+        match self {
+            TimelockAction::UpdateConfig(_)
+            | TimelockAction::SetDelay(_)
+            | TimelockAction::Upgrade(_) => Role::Owner,
+            TimelockAction::AllowToken(_) | TimelockAction::DisallowToken(_) => Role::Operator,
+            TimelockAction::EscheatPayout(_, _) => Role::Treasurer,
+        }
+    }
+}
+
+/// An action waiting in the timelock queue.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedAction {
+    /// Queue ID, sequential starting at 1.
+    pub id: u32,
+
+    /// Operation to perform.
+    pub action: TimelockAction,
+
+    /// Address that queued the action.
+    pub queued_by: Address,
+
+    /// Timestamp from which the action may be executed.
+    pub eta: u64,
+}
+
+impl QueuedAction {
+    /// Returns true once the delay has passed.
+    pub fn is_ready(&self, now: u64) -> bool {
+        now >= self.eta
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_required_role() {
+        let env = Env::default();
+        let token = Address::generate(&env);
+        assert_eq!(TimelockAction::AllowToken(token).required_role(), Role::Operator);
+        assert_eq!(TimelockAction::EscheatPayout(1, 0).required_role(), Role::Treasurer);
+        assert_eq!(TimelockAction::SetDelay(60).required_role(), Role::Owner);
+    }
+
+    #[test]
+    fn test_is_ready_after_eta() {
+        let env = Env::default();
+        let queued = QueuedAction {
+            id: 1,
+            action: TimelockAction::SetDelay(60),
+            queued_by: Address::generate(&env),
+            eta: 1_000,
+        };
+        assert!(!queued.is_ready(999));
+        assert!(queued.is_ready(1_000));
+    }
+}
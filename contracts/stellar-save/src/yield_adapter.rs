@@ -0,0 +1,19 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Cross-contract interface of a yield protocol idle contributions are
+/// deposited into.
+///
+/// Adapters wrap a lending market or vault behind share accounting: the
+/// contract transfers tokens to the adapter and calls `deposit` to be
+/// credited shares for them, and later redeems the shares with `withdraw`
+/// for the tokens plus whatever yield they earned.
+#[contractclient(name = "YieldAdapterClient")]
+pub trait YieldAdapter {
+    /// Credits `from` with shares for `amount` tokens already transferred
+    /// to the adapter. Returns the shares minted.
+    fn deposit(env: Env, from: Address, amount: i128) -> i128;
+
+    /// Redeems `shares` of `to` and transfers the underlying tokens to
+    /// `to`. Returns the tokens transferred.
+    fn withdraw(env: Env, to: Address, shares: i128) -> i128;
+}
@@ -6,6 +6,10 @@ cd "$(dirname "$0")/.."
 echo "Running contract tests..."
 cargo test --workspace --lib
 
+echo ""
+echo "Checking contract spec is up to date..."
+cargo xtask spec --check
+
 echo ""
 echo "Running frontend tests..."
 cd frontend
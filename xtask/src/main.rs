@@ -0,0 +1,576 @@
+//! Development tasks for Stellar-Save.
+//!
+//! `cargo xtask spec` extracts the contract's public entrypoints into
+//! `docs/contract-spec.json`, the machine-readable spec shared by the CLI, the
+//! SDK and the scenario tests. `cargo xtask spec --check` fails when the
+//! committed spec is stale or an entrypoint's annotations disagree with its
+//! body, so behavior and tooling cannot drift apart.
+//!
+//! Every public entrypoint carries a `# Spec` section in its doc comment:
+//!
+//! ```text
+//! /// # Spec
+//! /// * auth: `member`
+//! /// * emits: `contribution_made`, `late_contribution`
+//! ```
+//!
+//! `auth` lists the arguments that must authorize the call (or `admin` for
+//! the configured contract admin) and `emits` the event topics the call may
+//! publish; either is `none` when empty. State preconditions are read from
+//! the `Err(...)` entries of the `# Returns` section.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Contract source holding the `#[contractimpl]` block, relative to the workspace root.
+const CONTRACT_SOURCE: &str = "contracts/stellar-save/src/lib.rs";
+
+/// Generated spec, relative to the workspace root.
+const SPEC_OUTPUT: &str = "docs/contract-spec.json";
+
+/// Signer name standing for the configured contract admin.
+const CONTRACT_ADMIN: &str = "admin";
+
+/// Private helpers that authorize the address passed as their second argument.
+const AUTH_HELPERS: &[&str] = &["require_admin", "authorize_keeper_action"];
+
+struct Arg {
+    name: String,
+    ty: String,
+}
+
+struct Precondition {
+    error: String,
+    condition: String,
+}
+
+struct Entrypoint {
+    name: String,
+    summary: String,
+    args: Vec<Arg>,
+    returns: String,
+    auth: Vec<String>,
+    emits: Vec<String>,
+    preconditions: Vec<Precondition>,
+    has_spec: bool,
+    body: String,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("spec") => spec(args.iter().any(|arg| arg == "--check")),
+        _ => {
+            eprintln!("usage: cargo xtask spec [--check]");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives inside the workspace")
+        .to_path_buf()
+}
+
+fn spec(check: bool) -> ExitCode {
+    let root = workspace_root();
+    let source = match fs::read_to_string(root.join(CONTRACT_SOURCE)) {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("error: cannot read {CONTRACT_SOURCE}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let entrypoints = parse_entrypoints(&source);
+    let mut problems: Vec<String> = entrypoints.iter().flat_map(verify).collect();
+    let json = render_json(&entrypoints);
+    let output = root.join(SPEC_OUTPUT);
+
+    if check {
+        let current = fs::read_to_string(&output).unwrap_or_default();
+        if current != json {
+            problems.push(format!("{SPEC_OUTPUT} is stale; run `cargo xtask spec`"));
+        }
+    } else if problems.is_empty() {
+        if let Err(err) = fs::write(&output, &json) {
+            eprintln!("error: cannot write {SPEC_OUTPUT}: {err}");
+            return ExitCode::FAILURE;
+        }
+        println!("wrote {} entrypoints to {SPEC_OUTPUT}", entrypoints.len());
+    }
+
+    if problems.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+    for problem in &problems {
+        eprintln!("error: {problem}");
+    }
+    ExitCode::FAILURE
+}
+
+/// Finds every `pub fn` directly inside the `#[contractimpl]` block.
+fn parse_entrypoints(source: &str) -> Vec<Entrypoint> {
+    let lines: Vec<&str> = source.lines().collect();
+    let start = lines
+        .windows(2)
+        .position(|pair| {
+            pair[0].trim() == "#[contractimpl]" && pair[1].trim() == "impl StellarSaveContract {"
+        })
+        .map(|index| index + 2);
+    let Some(mut index) = start else {
+        return Vec::new();
+    };
+
+    let mut entrypoints = Vec::new();
+    let mut depth = 1i32;
+    while index < lines.len() && depth > 0 {
+        if depth == 1 && lines[index].trim_start().starts_with("pub fn ") {
+            let docs = doc_block(&lines, index);
+
+            // Signature runs up to the line opening the body
+            let mut signature = String::new();
+            let mut body_start = index;
+            loop {
+                signature.push_str(lines[body_start].trim());
+                signature.push(' ');
+                if code_of(lines[body_start]).trim_end().ends_with('{') {
+                    break;
+                }
+                body_start += 1;
+            }
+
+            // Body runs until the braces balance again
+            let mut balance = 0i32;
+            let mut end = index;
+            loop {
+                balance += brace_delta(lines[end]);
+                if end >= body_start && balance == 0 {
+                    break;
+                }
+                end += 1;
+            }
+
+            let body = lines[body_start + 1..end].join("\n");
+            entrypoints.push(build_entrypoint(&signature, &docs, body));
+            index = end + 1;
+            continue;
+        }
+        depth += brace_delta(lines[index]);
+        index += 1;
+    }
+
+    entrypoints
+}
+
+/// Collects the `///` lines directly above `index`, skipping attributes.
+fn doc_block(lines: &[&str], index: usize) -> Vec<String> {
+    let mut docs = Vec::new();
+    let mut cursor = index;
+    while cursor > 0 {
+        cursor -= 1;
+        let trimmed = lines[cursor].trim_start();
+        if let Some(doc) = trimmed.strip_prefix("///") {
+            docs.push(doc.strip_prefix(' ').unwrap_or(doc).trim_end().to_string());
+        } else if !trimmed.starts_with("#[") {
+            break;
+        }
+    }
+    docs.reverse();
+    docs
+}
+
+/// Returns the code part of a line, without any trailing `//` comment.
+fn code_of(line: &str) -> &str {
+    let bytes = line.as_bytes();
+    let mut in_string = false;
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' if in_string => index += 1,
+            b'"' => in_string = !in_string,
+            b'/' if !in_string && bytes.get(index + 1) == Some(&b'/') => return &line[..index],
+            _ => {}
+        }
+        index += 1;
+    }
+    line
+}
+
+/// Net number of braces opened on a line, ignoring strings and comments.
+fn brace_delta(line: &str) -> i32 {
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut delta = 0;
+    for c in code_of(line).chars() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' => delta += 1,
+            '}' => delta -= 1,
+            _ => {}
+        }
+    }
+    delta
+}
+
+fn build_entrypoint(signature: &str, docs: &[String], body: String) -> Entrypoint {
+    let after_fn = &signature["pub fn ".len()..];
+    let name_end = after_fn.find(['(', '<']).unwrap_or(after_fn.len());
+    let name = after_fn[..name_end].trim().to_string();
+
+    let open = signature.find('(').unwrap_or(0);
+    let close = matching_paren(signature, open);
+    let args = split_top_level(&signature[open + 1..close])
+        .into_iter()
+        .filter_map(|arg| {
+            let (arg_name, ty) = arg.split_once(':')?;
+            let arg_name = arg_name.trim().trim_start_matches("mut ").to_string();
+            (arg_name != "env").then(|| Arg {
+                name: arg_name,
+                ty: ty.trim().to_string(),
+            })
+        })
+        .collect();
+
+    let rest = &signature[close + 1..];
+    let returns = match rest.find("->") {
+        Some(arrow) => rest[arrow + 2..].trim().trim_end_matches('{').trim().to_string(),
+        None => "()".to_string(),
+    };
+
+    let mut entrypoint = Entrypoint {
+        name,
+        summary: String::new(),
+        args,
+        returns,
+        auth: Vec::new(),
+        emits: Vec::new(),
+        preconditions: Vec::new(),
+        has_spec: false,
+        body,
+    };
+    parse_docs(docs, &mut entrypoint);
+    entrypoint
+}
+
+fn matching_paren(text: &str, open: usize) -> usize {
+    let mut depth = 0;
+    for (index, c) in text.char_indices().skip_while(|(index, _)| *index < open) {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return index;
+                }
+            }
+            _ => {}
+        }
+    }
+    text.len()
+}
+
+/// Splits a comma-separated list, ignoring commas nested in `<>`, `()` or `[]`.
+fn split_top_level(list: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    for c in list.chars() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+    parts
+}
+
+fn parse_docs(docs: &[String], entrypoint: &mut Entrypoint) {
+    let mut summary = Vec::new();
+    let mut in_summary = true;
+    let mut in_entry = false;
+    let mut section = "";
+
+    for line in docs {
+        if let Some(heading) = line.strip_prefix("# ") {
+            section = heading.trim();
+            in_summary = false;
+            if section == "Spec" {
+                entrypoint.has_spec = true;
+            }
+            continue;
+        }
+        if in_summary {
+            if line.is_empty() {
+                in_summary = summary.is_empty();
+            } else {
+                summary.push(line.trim());
+            }
+            continue;
+        }
+
+        match section {
+            "Spec" => {
+                if let Some(list) = line.trim().strip_prefix("* auth:") {
+                    entrypoint.auth = parse_list(list);
+                } else if let Some(list) = line.trim().strip_prefix("* emits:") {
+                    entrypoint.emits = parse_list(list);
+                }
+            }
+            "Returns" => {
+                let trimmed = line.trim();
+                if let Some(rest) = trimmed.strip_prefix("* `Err(StellarSaveError::") {
+                    let (error, condition) = rest.split_once(")`").unwrap_or((rest, ""));
+                    entrypoint.preconditions.push(Precondition {
+                        error: error.to_string(),
+                        condition: condition.trim().trim_start_matches('-').trim().to_string(),
+                    });
+                    in_entry = true;
+                } else if trimmed.is_empty() || trimmed.starts_with('*') {
+                    in_entry = false;
+                } else if in_entry {
+                    // Continuation of the previous entry
+                    if let Some(last) = entrypoint.preconditions.last_mut() {
+                        last.condition.push(' ');
+                        last.condition.push_str(trimmed);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    entrypoint.summary = summary.join(" ");
+}
+
+fn parse_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(|item| item.trim().trim_matches('`').to_string())
+        .filter(|item| !item.is_empty() && item != "none")
+        .collect()
+}
+
+/// Checks an entrypoint's annotations against its body.
+fn verify(entrypoint: &Entrypoint) -> Vec<String> {
+    let name = &entrypoint.name;
+    if !entrypoint.has_spec {
+        return vec![format!("`{name}` has no `# Spec` section")];
+    }
+
+    let mut problems = Vec::new();
+    for signer in &entrypoint.auth {
+        if signer != CONTRACT_ADMIN && !entrypoint.args.iter().any(|arg| &arg.name == signer) {
+            problems.push(format!("`{name}` declares auth for unknown argument `{signer}`"));
+        }
+    }
+    for signer in authorized_in_body(entrypoint) {
+        if !entrypoint.auth.contains(&signer) {
+            problems.push(format!("`{name}` requires auth from `{signer}` but does not declare it"));
+        }
+    }
+    for topic in events_in_body(&entrypoint.body) {
+        if !entrypoint.emits.contains(&topic) {
+            problems.push(format!("`{name}` emits `{topic}` but does not declare it"));
+        }
+    }
+    problems
+}
+
+/// Arguments the body calls `require_auth` on, directly or through an auth helper.
+fn authorized_in_body(entrypoint: &Entrypoint) -> Vec<String> {
+    let mut signers = Vec::new();
+    for arg in &entrypoint.args {
+        let direct = format!("{}.require_auth()", arg.name);
+        let via_helper = AUTH_HELPERS.iter().any(|helper| {
+            entrypoint.body.contains(&format!("Self::{helper}(&env, &{})", arg.name))
+        });
+        if entrypoint.body.contains(&direct) || via_helper {
+            signers.push(arg.name.clone());
+        }
+    }
+    signers
+}
+
+/// Event topics published directly in a body.
+fn events_in_body(body: &str) -> Vec<String> {
+    let mut topics: Vec<String> = Vec::new();
+    let mut push = |topic: &str| {
+        if !topics.iter().any(|existing| existing == topic) {
+            topics.push(topic.to_string());
+        }
+    };
+
+    // Typed events: EventEmitter::emit_<topic>(...) and emit_group_activated(...)
+    let mut rest = body;
+    while let Some(found) = rest.find("emit_") {
+        let tail = &rest[found + "emit_".len()..];
+        let end = tail
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(tail.len());
+        if tail[end..].starts_with('(') {
+            push(&tail[..end]);
+        }
+        rest = &tail[end..];
+    }
+
+    // Ad hoc events: publish((Symbol::new(&env, "<topic>"), ...), ...)
+    let mut rest = body;
+    while let Some(found) = rest.find("Symbol::new(") {
+        let tail = &rest[found + "Symbol::new(".len()..];
+        let call = &tail[..tail.find(')').unwrap_or(tail.len())];
+        if let Some(open) = call.find('"') {
+            if let Some(len) = call[open + 1..].find('"') {
+                push(&call[open + 1..open + 1 + len]);
+            }
+        }
+        rest = tail;
+    }
+
+    topics
+}
+
+fn render_json(entrypoints: &[Entrypoint]) -> String {
+    let mut out = String::from("{\n  \"contract\": \"StellarSaveContract\",\n  \"entrypoints\": [\n");
+    for (index, entrypoint) in entrypoints.iter().enumerate() {
+        out.push_str("    {\n");
+        out.push_str(&format!("      \"name\": {},\n", quote(&entrypoint.name)));
+        out.push_str(&format!("      \"summary\": {},\n", quote(&entrypoint.summary)));
+
+        let args: Vec<String> = entrypoint
+            .args
+            .iter()
+            .map(|arg| format!("{{ \"name\": {}, \"type\": {} }}", quote(&arg.name), quote(&arg.ty)))
+            .collect();
+        out.push_str(&format!("      \"args\": {},\n", json_list(&args, 8)));
+        out.push_str(&format!("      \"returns\": {},\n", quote(&entrypoint.returns)));
+
+        let auth: Vec<String> = entrypoint.auth.iter().map(|signer| quote(signer)).collect();
+        out.push_str(&format!("      \"auth\": {},\n", json_list(&auth, 8)));
+        let emits: Vec<String> = entrypoint.emits.iter().map(|topic| quote(topic)).collect();
+        out.push_str(&format!("      \"emits\": {},\n", json_list(&emits, 8)));
+
+        let preconditions: Vec<String> = entrypoint
+            .preconditions
+            .iter()
+            .map(|pre| {
+                format!("{{ \"error\": {}, \"condition\": {} }}", quote(&pre.error), quote(&pre.condition))
+            })
+            .collect();
+        out.push_str(&format!("      \"preconditions\": {}\n", json_list(&preconditions, 8)));
+
+        out.push_str(if index + 1 < entrypoints.len() { "    },\n" } else { "    }\n" });
+    }
+    out.push_str("  ]\n}\n");
+    out
+}
+
+fn json_list(items: &[String], indent: usize) -> String {
+    if items.is_empty() {
+        return "[]".to_string();
+    }
+    let pad = " ".repeat(indent);
+    let inner: Vec<String> = items.iter().map(|item| format!("{pad}{item}")).collect();
+    format!("[\n{}\n{}]", inner.join(",\n"), " ".repeat(indent - 2))
+}
+
+fn quote(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+#[contractimpl]
+impl StellarSaveContract {
+    /// Contributes to a group.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Done
+    /// * `Err(StellarSaveError::NotMember)` - Caller is not a member
+    ///   of the group
+    ///
+    /// # Spec
+    /// * auth: `member`
+    /// * emits: `contribution_made`
+    pub fn contribute(env: Env, group_id: u64, member: Address) -> Result<(), StellarSaveError> {
+        member.require_auth();
+        if true { let _ = "}"; }
+        EventEmitter::emit_contribution_made(&env, group_id);
+        Ok(())
+    }
+
+    fn helper(env: &Env) {}
+
+    /// Reads a value.
+    pub fn get_value(env: Env) -> u32 {
+        env.events().publish((Symbol::new(&env, "value_read"),), 1);
+        0
+    }
+}
+"#;
+
+    #[test]
+    fn test_parse_entrypoints() {
+        let entrypoints = parse_entrypoints(SAMPLE);
+        assert_eq!(entrypoints.len(), 2);
+
+        let contribute = &entrypoints[0];
+        assert_eq!(contribute.name, "contribute");
+        assert_eq!(contribute.summary, "Contributes to a group.");
+        assert_eq!(contribute.args.len(), 2);
+        assert_eq!(contribute.returns, "Result<(), StellarSaveError>");
+        assert_eq!(contribute.auth, vec!["member"]);
+        assert_eq!(contribute.emits, vec!["contribution_made"]);
+        assert_eq!(contribute.preconditions[0].error, "NotMember");
+        assert_eq!(contribute.preconditions[0].condition, "Caller is not a member of the group");
+        assert!(verify(contribute).is_empty());
+    }
+
+    #[test]
+    fn test_verify_reports_missing_annotations() {
+        let entrypoints = parse_entrypoints(SAMPLE);
+        let problems = verify(&entrypoints[1]);
+        assert_eq!(problems, vec!["`get_value` has no `# Spec` section"]);
+
+        let mut contribute = parse_entrypoints(SAMPLE).remove(0);
+        contribute.auth.clear();
+        contribute.emits.clear();
+        assert_eq!(verify(&contribute).len(), 2);
+    }
+}